@@ -0,0 +1,59 @@
+use std::fs;
+use crate::{Error, Result, Part};
+use crate::str::StringOps;
+
+/// An entry of the answers manifest: the answer a day's part is expected
+/// to give for a given input file.
+#[derive(Debug)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    /// Steps to walk instead of the puzzle's, for day 21's examples
+    pub steps: Vec<u64>,
+    pub answer: String
+}
+
+impl ExpectedAnswer {
+
+    fn parse(line: &str) -> Result<ExpectedAnswer> {
+        let (day_str, rest) = line.try_split_once(" ")?;
+        let (part_str, rest) = rest.try_split_once(" ")?;
+        let (input, rest) = rest.try_split_once(" ")?;
+        let (steps, answer) = match rest.strip_prefix("steps=") {
+            Some(rest) => {
+                let (steps_str, answer) = rest.try_split_once(" ")?;
+                (steps_str.split(',').map(|s| s.try_parse()).collect::<Result<_>>()?, answer)
+            },
+            None => (vec![], rest)
+        };
+        let part = match part_str {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(Error::parse(part_str, "expected part 1 or 2"))
+        };
+
+        Ok(ExpectedAnswer {
+            day: day_str.try_parse()?,
+            part,
+            input: input.to_string(),
+            steps,
+            answer: answer.to_string()
+        })
+    }
+
+    pub fn file_path(&self) -> String {
+        format!("inputs/{}", self.input)
+    }
+
+}
+
+/// Reads the answers manifest, skipping blank lines and `#` comments.
+pub fn load(file_path: &str) -> Result<Vec<ExpectedAnswer>> {
+    fs::read_to_string(file_path)?
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(ExpectedAnswer::parse)
+        .collect()
+}
@@ -0,0 +1,163 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use crate::{Result, Part, Solution};
+
+/// Summary of the durations measured over all the iterations, in microseconds.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64
+}
+
+/// Timings of a day's part on one input, with parsing measured apart from solving.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats
+}
+
+/// A benchmark whose median got slower than in the baseline by more than the tolerance.
+pub struct Regression<'a> {
+    pub benchmark: &'a Benchmark,
+    pub phase: &'static str,
+    pub baseline_median: f64,
+    pub median: f64
+}
+
+impl Stats {
+
+    fn from_samples(mut samples: Vec<f64>) -> Stats {
+        samples.sort_by(f64::total_cmp);
+
+        let n = samples.len();
+        // Both indices are the middle sample when n is odd, or the two middle ones when it's even
+        let median = (samples[(n - 1) / 2] + samples[n / 2]) / 2.0;
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats { min: samples[0], median, mean, stddev: variance.sqrt() }
+    }
+
+}
+
+impl Benchmark {
+
+    /// Parses and solves the input `warmup` times without measuring, then
+    /// `iterations` more times timing each phase.
+    pub fn run(
+        day: u8,
+        part: Part,
+        file_path: &str,
+        warmup: usize,
+        iterations: usize,
+        parse: impl Fn(&str) -> Result<Box<dyn Solution>>
+    ) -> Result<Benchmark> {
+        if iterations == 0 {
+            return Err("At least one iteration is needed".into());
+        }
+
+        let content = fs::read_to_string(file_path)?;
+        for _ in 0 .. warmup {
+            parse(&content)?.solve(part)?;
+        }
+
+        let mut parse_samples = vec![];
+        let mut solve_samples = vec![];
+        for _ in 0 .. iterations {
+            let time = Instant::now();
+            let solution = parse(&content)?;
+            parse_samples.push(time.elapsed().as_secs_f64() * 1e6);
+
+            let time = Instant::now();
+            solution.solve(part)?;
+            solve_samples.push(time.elapsed().as_secs_f64() * 1e6);
+        }
+
+        Ok(Benchmark {
+            day,
+            part: part.number(),
+            input: file_path.to_string(),
+            iterations,
+            parse: Stats::from_samples(parse_samples),
+            solve: Stats::from_samples(solve_samples)
+        })
+    }
+
+    /// Finds the phases whose median is slower than in the matching baseline
+    /// benchmark by more than `tolerance` (e.g. 0.1 for 10%).
+    pub fn regressions<'a>(&'a self, baseline: &[Benchmark], tolerance: f64) -> Vec<Regression<'a>> {
+        let Some(base) = baseline
+            .iter()
+            .find(|b| b.day == self.day && b.part == self.part && b.input == self.input)
+        else {
+            return vec![];
+        };
+
+        [("parse", &base.parse, &self.parse), ("solve", &base.solve, &self.solve)]
+            .into_iter()
+            .filter(|(_, base_stats, stats)| stats.median > base_stats.median * (1.0 + tolerance))
+            .map(|(phase, base_stats, stats)| Regression {
+                benchmark: self,
+                phase,
+                baseline_median: base_stats.median,
+                median: stats.median
+            })
+            .collect()
+    }
+
+}
+
+pub fn save(file_path: &str, benchmarks: &[Benchmark]) -> Result<()> {
+    let json = serde_json::to_string_pretty(benchmarks).map_err(|err| err.to_string())?;
+    fs::write(file_path, json)?;
+    Ok(())
+}
+
+pub fn load(file_path: &str) -> Result<Vec<Benchmark>> {
+    let json = fs::read_to_string(file_path)?;
+    Ok(serde_json::from_str(&json).map_err(|err| format!("Invalid baseline \"{file_path}\": {err}"))?)
+}
+
+impl Display for Stats {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:.1} µs, median {:.1} µs, mean {:.1} µs, stddev {:.1} µs",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+
+}
+
+impl Display for Benchmark {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Benchmark for \"{}\" (part{}), {} iterations:", self.input, self.part, self.iterations)?;
+        writeln!(f, "parse: {}", self.parse)?;
+        write!(f, "solve: {}", self.solve)
+    }
+
+}
+
+impl Display for Regression<'_> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Regression in day {} part {} {} on \"{}\": median {:.1} µs, was {:.1} µs ({:+.1}%)",
+            self.benchmark.day, self.benchmark.part, self.phase, self.benchmark.input,
+            self.median, self.baseline_median,
+            (self.median / self.baseline_median - 1.0) * 100.0
+        )
+    }
+
+}
@@ -0,0 +1,276 @@
+use std::{env, fs};
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::BufWriter;
+use std::rc::Rc;
+use log::LevelFilter;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use adventofcode2023::{Error, Result, Part, Solution, answers, dot, run, summary};
+use adventofcode2023::bench::{self, Benchmark};
+use adventofcode2023::days::{self, day05, day20, day21, day22};
+use adventofcode2023::summary::Status;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Diagnostics to print, as `RUST_LOG`-style filters over the log targets (e.g. "day14=debug,day20::pulses=trace")
+    #[arg(long, global = true)]
+    log: Option<String>
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a single day against one input
+    Run {
+        #[command(flatten)]
+        target: Target
+    },
+
+    /// Times the solution of a single day against one input over several iterations
+    Bench {
+        #[command(flatten)]
+        target: Target,
+
+        /// Untimed iterations to run first
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed iterations
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+
+        /// File to write the results to, as JSON
+        #[arg(long)]
+        json: Option<String>,
+
+        /// JSON results of a previous run to compare against
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Percentage a median may grow over the baseline before being flagged
+        #[arg(long, default_value_t = 10.0)]
+        tolerance: f64,
+    },
+
+    /// Runs every day against its input in parallel and checks the known answers
+    All {
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+
+    /// Prints the graph described by the input of day 8, 19 or 20 in Graphviz DOT
+    Graph {
+        /// Day whose input to export
+        #[arg(long)]
+        day: u8,
+
+        /// Either "input", "example", "exampleN" (looked up under `inputs/`) or a file path
+        #[arg(long, default_value = "input")]
+        input: String,
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+enum Format {
+    Table,
+    Markdown,
+    Json
+}
+
+#[derive(Args)]
+struct Target {
+    /// Day to run
+    #[arg(long)]
+    day: u8,
+
+    /// Part to run, both parts are run if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Either "input", "example", "exampleN" (looked up under `inputs/`) or a file path
+    #[arg(long, default_value = "input")]
+    input: String,
+
+    /// How day 5's part 2 goes through the seed ranges
+    #[arg(long, value_enum)]
+    strategy: Option<day05::Part2Strategy>,
+
+    /// Seed for the random sample of day 5's verify strategy, to reproduce an earlier run (drawn at random otherwise)
+    #[arg(long)]
+    sample_seed: Option<u64>,
+
+    /// Numbers of steps to walk in day 21, comma separated (defaults to 6 for examples, otherwise 64 in part 1 and 26501365 in part 2)
+    #[arg(long, value_delimiter = ',')]
+    steps: Vec<u64>,
+
+    /// File to write every pulse sent in day 20 to
+    #[arg(long)]
+    events: Option<String>,
+
+    /// Shows day 22's bricks in a window once settled, which requires the `viz` feature
+    #[arg(long)]
+    render: bool,
+
+    /// File to write day 22's settled bricks to, as an OBJ mesh if it ends in `.obj` or as text otherwise
+    #[arg(long)]
+    dump: Option<String>,
+}
+
+type ParseFn = Box<dyn Fn(&str) -> Result<Box<dyn Solution>>>;
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_logging(cli.log.as_deref());
+
+    match cli.command {
+        Command::Run { target } => {
+            let parse = target.parse_fn()?;
+            for part in target.parts() {
+                run(&target.file_path(), part, |content, part| parse(content)?.solve(part));
+            }
+        },
+        Command::Bench { target, warmup, iterations, json, baseline, tolerance } => {
+            let parse = target.parse_fn()?;
+            let mut benchmarks = vec![];
+            for part in target.parts() {
+                let benchmark = Benchmark::run(target.day, part, &target.file_path(), warmup, iterations, &parse)?;
+                println!("{benchmark}");
+                println!();
+                benchmarks.push(benchmark);
+            }
+
+            if let Some(json) = json {
+                bench::save(&json, &benchmarks)?;
+            }
+
+            if let Some(baseline) = baseline {
+                let baseline = bench::load(&baseline)?;
+                let regressions: Vec<_> = benchmarks
+                    .iter()
+                    .flat_map(|b| b.regressions(&baseline, tolerance / 100.0))
+                    .collect();
+
+                for regression in &regressions {
+                    println!("{regression}");
+                }
+                if !regressions.is_empty() {
+                    return Err(format!("{} regression(s) over the baseline", regressions.len()).into());
+                }
+            }
+        },
+        Command::All { format } => {
+            let expected_answers = answers::load("inputs/answers")?;
+            let days: Vec<_> = days::DAYS.iter().collect();
+
+            let outcomes = summary::run_all(&days, &expected_answers);
+            match format {
+                Format::Table => print!("{}", summary::to_table(&outcomes)),
+                Format::Markdown => print!("{}", summary::to_markdown(&outcomes)),
+                Format::Json => println!("{}", summary::to_json(&outcomes)?)
+            }
+
+            let num_failed = outcomes.iter().filter(|o| o.status == Status::Fail).count();
+            if num_failed > 0 {
+                return Err(format!("{num_failed} answer(s) don't match the expected ones").into());
+            }
+        },
+        Command::Graph { day, input } => {
+            let file_path = input_file_path(day, &input);
+            let content = fs::read_to_string(&file_path).map_err(|err| Error::from(err).in_file(&file_path))?;
+            let graph = dot::graph(day, &content).map_err(|err| err.locate(&content).in_file(&file_path))?;
+            print!("{graph}");
+        }
+    }
+
+    Ok(())
+}
+
+fn is_example(input: &str) -> bool {
+    input
+        .strip_prefix("example")
+        .is_some_and(|suffix| suffix.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn input_file_path(day: u8, input: &str) -> String {
+    if input == "input" {
+        format!("inputs/day{day:02}")
+    } else if is_example(input) {
+        format!("inputs/day{day:02}_{input}")
+    } else {
+        input.to_string()
+    }
+}
+
+// Silent unless asked for, through either the RUST_LOG env var or the --log flag (which takes precedence)
+fn init_logging(filters: Option<&str>) {
+    let mut logger = env_logger::Builder::new();
+    logger.filter_level(LevelFilter::Off);
+    if let Ok(env_filters) = env::var("RUST_LOG") {
+        logger.parse_filters(&env_filters);
+    }
+    if let Some(filters) = filters {
+        logger.parse_filters(filters);
+    }
+    logger
+        .format_timestamp(None)
+        .format_target(true)
+        .init();
+}
+
+impl Target {
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => vec![Part::One, Part::Two]
+        }
+    }
+
+    fn is_example(&self) -> bool {
+        is_example(&self.input)
+    }
+
+    fn file_path(&self) -> String {
+        input_file_path(self.day, &self.input)
+    }
+
+    fn parse_fn(&self) -> Result<ParseFn> {
+        let day = days::get(self.day).ok_or(format!("Day {} is not implemented", self.day))?;
+        if self.day == 5 && (self.strategy.is_some() || self.sample_seed.is_some()) {
+            let strategy = self.strategy.unwrap_or_default();
+            let sample_seed = self.sample_seed;
+            Ok(Box::new(move |content| {
+                let almanac = day05::Almanac::parse(content)?.with_strategy(strategy);
+                Ok(Box::new(match sample_seed {
+                    Some(sample_seed) => almanac.with_sample_seed(sample_seed),
+                    None => almanac
+                }))
+            }))
+        } else if self.day == 21 {
+            let steps = if self.steps.is_empty() && self.is_example() { vec![6] } else { self.steps.clone() };
+            Ok(Box::new(move |content| Ok(Box::new(day21::Garden::parse(content)?.with_steps(steps.clone())))))
+        } else if let (20, Some(events)) = (self.day, &self.events) {
+            // Created once and shared, as the input is parsed again for each part
+            let event_log: day20::EventLog = Rc::new(RefCell::new(BufWriter::new(File::create(events)?)));
+            Ok(Box::new(move |content| Ok(Box::new(day20::ModuleNetwork::parse(content)?.with_event_log(event_log.clone())))))
+        } else if self.day == 22 && (self.render || self.dump.is_some()) {
+            let render = self.render;
+            let dump = self.dump.clone();
+            Ok(Box::new(move |content| {
+                let stack = day22::SandStack::parse(content)?;
+                if let Some(dump) = &dump {
+                    let bricks = if dump.ends_with(".obj") { stack.to_obj() } else { stack.to_text() };
+                    fs::write(dump, bricks)?;
+                }
+                Ok(Box::new(if render { stack.with_rendering() } else { stack }))
+            }))
+        } else {
+            Ok(Box::new(|content| day.parse(content)))
+        }
+    }
+
+}
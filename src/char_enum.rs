@@ -0,0 +1,61 @@
+/// Declares a `#[repr(u8)]` enum whose variants each stand for one (ASCII) character
+/// of the input, along with a checked `TryFrom<char>` and a `Display` printing that character.
+///
+/// ```
+/// use adventofcode2023::char_enum;
+///
+/// char_enum! {
+///     #[derive(PartialEq, Debug)]
+///     enum Tile {
+///         Empty = b'.',
+///         Rock = b'#',
+///     }
+/// }
+///
+/// assert_eq!(Tile::try_from('#').unwrap(), Tile::Rock);
+/// assert!(Tile::try_from('x').is_err());
+/// assert_eq!(Tile::Empty.to_string(), ".");
+/// ```
+#[macro_export]
+macro_rules! char_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $byte:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u8)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant = $byte),*
+        }
+
+        impl TryFrom<char> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(c: char) -> $crate::Result<$name> {
+                match u8::try_from(c) {
+                    $(Ok($byte) => Ok($name::$variant),)*
+                    _ => {
+                        let expected: Vec<String> = [$($byte),*]
+                            .iter()
+                            .map(|&b| format!("'{}'", char::from(b)))
+                            .collect();
+                        Err(format!("expected {} to be one of {}", stringify!($name), expected.join(", ")).into())
+                    }
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let byte: u8 = match self {
+                    $($name::$variant => $byte),*
+                };
+                write!(f, "{}", char::from(byte))
+            }
+
+        }
+    };
+}
@@ -0,0 +1,79 @@
+use crate::{Error, Result, Solution};
+use crate::str::StringOps;
+
+const DIGIT_NAMES: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+pub struct CalibrationDocument {
+    lines: Vec<String>
+}
+
+impl Solution for CalibrationDocument {
+
+    fn parse(content: &str) -> Result<CalibrationDocument> {
+        // Part 1 only looks for numeric digits, so lines with just spelled out ones fail there instead
+        if let Some(line) = content.lines().find(|l| get_first_digit(l, false).is_none()) {
+            return Err(Error::parse(line, "expected a digit, either numeric or spelled out"));
+        }
+
+        Ok(CalibrationDocument { lines: content.lines().map(String::from).collect() })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.sum_calibration_values(parse_digits_part1)?.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.sum_calibration_values(parse_digits_part2)?.to_string())
+    }
+
+}
+
+impl CalibrationDocument {
+
+    fn sum_calibration_values(&self, parse_digits: fn(&str) -> Vec<char>) -> Result<u32> {
+        self.lines
+            .iter()
+            .map(|l| {
+                let digits = parse_digits(l);
+                match (digits.first(), digits.last()) {
+                    (Some(first), Some(last)) => format!("{first}{last}").try_parse::<u32>(),
+                    _ => Err(Error::parse(l, "expected a numeric digit"))
+                }
+            })
+            .sum()
+    }
+
+}
+
+fn parse_digits_part1(line: &str) -> Vec<char> {
+    line.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+fn parse_digits_part2(line: &str) -> Vec<char> {
+    get_first_digit(line, false)
+        .into_iter()
+        .chain(get_first_digit(line, true))
+        .collect()
+}
+
+fn get_first_digit(line: &str, from_end: bool) -> Option<char> {
+    let line_it: Box<dyn Iterator<Item = (usize, char)>> = if from_end { 
+        Box::new(line.char_indices().rev())
+    } else { 
+        Box::new(line.char_indices())
+    };
+
+    for (i, c) in line_it {
+        if c.is_ascii_digit() {
+            return Some(c);
+        }
+        
+        for (name, digit) in DIGIT_NAMES.iter().zip(1 ..= 9) {
+            if line[i..].starts_with(name) {
+                return char::from_digit(digit, 10);
+            }
+        }
+    }
+
+    None
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use crate::{Error, Result, Solution};
+use crate::str::StringOps;
+
+struct Play {
+    red_dice_count: u8,
+    blue_dice_count: u8,
+    green_dice_count: u8
+}
+
+struct Counters {
+    game_id: u8,
+    red: u8,
+    blue: u8,
+    green: u8
+}
+
+struct Game {
+    id: u8,
+    plays: Vec<Play>
+}
+
+pub struct GameRecord {
+    games: Vec<Game>
+}
+
+impl Solution for GameRecord {
+
+    fn parse(content: &str) -> Result<GameRecord> {
+        let games = content
+            .lines()
+            .map(Game::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(GameRecord { games })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.game_counts()
+            .filter(|c| c.red <= 12 && c.green <= 13 && c.blue <= 14)
+            .map(|c| u32::from(c.game_id))
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.game_counts()
+            .map(|c| u32::from(c.red) * u32::from(c.green) * u32::from(c.blue))
+            .sum::<u32>()
+            .to_string())
+    }
+
+}
+
+impl GameRecord {
+
+    fn game_counts(&self) -> impl Iterator<Item = Counters> + '_ {
+        self.games
+            .iter()
+            .map(|g| Counters {
+                game_id: g.id,
+                red: g.get_max_count(|p| p.red_dice_count),
+                green: g.get_max_count(|p| p.green_dice_count),
+                blue: g.get_max_count(|p| p.blue_dice_count)
+            })
+    }
+
+}
+
+impl Game {
+    fn parse(line: &str) -> Result<Game> {
+        let (game_id_str, plays_str) = line.try_split_once(": ")?;
+        let (_, id_str) = game_id_str.try_split_once(" ")?;
+        let play_strs = plays_str.split("; ");
+
+        Ok(Game {
+            id: id_str.try_parse()?,
+            plays: play_strs.map(Play::parse).collect::<Result<_>>()?
+        })
+    }
+
+    fn get_max_count(&self, get_count: fn(&Play) -> u8) -> u8 {
+        self.plays.iter().map(get_count).max().unwrap()
+    }
+}
+
+impl Play {
+    fn parse(play_str: &str) -> Result<Play> {
+        let mut play = Play {
+            blue_dice_count: 0,
+            green_dice_count: 0,
+            red_dice_count: 0
+        };
+        for dice_str in play_str.split(", ") {
+            let (count, color) = dice_str.try_split_once(" ")?;
+            match color {
+                "blue" => play.blue_dice_count = count.try_parse()?,
+                "red" => play.red_dice_count = count.try_parse()?,
+                "green" => play.green_dice_count = count.try_parse()?,
+                _ => return Err(Error::parse(color, "unknown color"))
+            }
+        }
+
+        Ok(play)
+    }
+}
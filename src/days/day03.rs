@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+use crate::Solution;
+use crate::Result;
+use crate::grid::Grid;
+use crate::str::StringOps;
+
+pub struct EngineSchematic {
+    rep: Grid<char>
+}
+
+impl Solution for EngineSchematic {
+    fn parse(content: &str) -> Result<EngineSchematic> {
+        Ok(EngineSchematic { rep: Grid::parse(content, Ok)? })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.get_part_numbers()?.iter().sum::<u32>().to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.get_gear_ratios()?.iter().sum::<u32>().to_string())
+    }
+}
+
+impl EngineSchematic {
+
+    fn get_part_numbers(&self) -> Result<Vec<u32>> {
+        let mut part_numbers = Vec::<u32>::new();
+        for r in 0 .. self.rep.num_rows() {
+            let row = &self.rep[r];
+            let mut curr_n = String::new();
+            let mut curr_is_part = false;
+        
+            for (c, &v) in row.iter().enumerate() {
+                if v.is_numeric() {
+                    curr_n.push(v);
+                    if !curr_is_part && !self.get_adjacent_symbols(r, c).is_empty() {
+                        curr_is_part = true;
+                    }
+                } else {
+                    if curr_is_part {
+                        part_numbers.push(curr_n.try_parse()?);
+                    }
+                    curr_is_part = false;
+                    curr_n.clear();
+                }
+            }
+
+            if curr_is_part {
+                part_numbers.push(curr_n.try_parse()?);
+            }
+        }
+        Ok(part_numbers)
+    }
+
+    fn get_adjacent_symbols(&self, curr_r: usize, curr_c: usize) -> Vec<(usize, usize)> {
+        self.rep
+            .neighbors8(curr_r, curr_c)
+            .filter(|&(r, c)| self.rep[r][c] != '.' && !self.rep[r][c].is_numeric())
+            .collect()
+    }
+
+    fn get_gear_ratios(&self) -> Result<Vec<u32>> {
+        let mut gears = HashMap::<(usize, usize), Vec<u32>>::new();
+        for r in 0 .. self.rep.num_rows() {
+            let row = &self.rep[r];
+            let mut curr_n = String::new();
+            let mut curr_gears = HashSet::<(usize, usize)>::new();
+        
+            for (c, &v) in row.iter().enumerate() {
+                if v.is_numeric() {
+                    curr_n.push(v);
+                    self.get_adjacent_symbols(r, c)
+                        .iter()
+                        .filter(|s| self.rep[s.0][s.1] == '*')
+                        .for_each(|s| { curr_gears.insert(*s); });
+                } else {
+                    if !curr_gears.is_empty() {
+                        let new_n = curr_n.try_parse()?;
+                        for gear_pos in curr_gears.drain() {
+                            gears
+                                .entry(gear_pos)
+                                .and_modify(|n| n.push(new_n))
+                                .or_insert(vec![new_n]);
+                        }
+                    }
+                    curr_n.clear();
+                }
+            }
+
+            if !curr_gears.is_empty() {
+                let new_n = curr_n.try_parse()?;
+                for gear_pos in curr_gears.drain() {
+                    gears
+                        .entry(gear_pos)
+                        .and_modify(|n| n.push(new_n))
+                        .or_insert(vec![new_n]);
+                }
+            }
+        }
+        Ok(
+            gears
+                .values()
+                .filter(|ns| ns.len() == 2)
+                .map(|ns| ns[0] * ns[1])
+                .collect()
+        )
+    } 
+}
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use crate::{Result, Solution};
+use crate::str::StringOps;
+
+struct ScratchCard {
+    winners: HashSet<u32>,
+    mine: HashSet<u32>
+}
+
+pub struct ScratchCardPile {
+    cards: Vec<ScratchCard>
+}
+
+impl Solution for ScratchCardPile {
+
+    fn parse(content: &str) -> Result<ScratchCardPile> {
+        let cards = content
+            .lines()
+            .map(ScratchCard::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(ScratchCardPile { cards })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.cards.iter().map(|c| c.score()).sum::<u32>().to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let mut card_counts = vec![0_u32; self.cards.len()];
+        for (idx, c) in self.cards.iter().enumerate() {
+            card_counts[idx] += 1;
+            
+            for next_idx in (idx + 1) .. (idx + 1 + c.num_won_matches() as usize) {
+                card_counts[next_idx] += card_counts[idx];
+            }
+        }
+        Ok(card_counts.iter().sum::<u32>().to_string())
+    }
+
+}
+
+impl ScratchCard {
+
+    fn parse(line: &str) -> Result<ScratchCard> {
+        let (_, card_content) = line.try_split_once(": ")?;
+        let (winners_str, mine_str) = card_content.try_split_once(" | ")?;
+        Ok(ScratchCard { 
+            winners: winners_str.try_parse_sequence::<u32>()?.into_iter().collect(),
+            mine: mine_str.try_parse_sequence::<u32>()?.into_iter().collect()
+        })
+    }
+
+    fn num_won_matches(&self) -> u32 {
+        self.winners.intersection(&self.mine).count() as u32
+    }
+
+    fn score(&self) -> u32 {
+        let num_matches = self.num_won_matches();
+        if num_matches == 0 {
+            0
+        } else {
+            2_i32.pow(num_matches - 1) as u32
+        }
+    }
+
+}
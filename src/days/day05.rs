@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use clap::ValueEnum;
+use log::debug;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
+use crate::{Error, Result, Solution};
+use crate::interval::{Interval, IntervalSet};
+use crate::str::StringOps;
+
+/// How part 2 goes through the seed ranges.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum Part2Strategy {
+    /// Plants every seed one by one (used to run in < 1h)
+    EverySeed,
+    /// Plants every seed one by one, in parallel (used to run in ~12 minutes)
+    EverySeedInParallel,
+    /// Converts whole ranges of seeds at once (runs in a few milliseconds)
+    #[default]
+    Ranges,
+    /// Converts whole ranges and checks the answer against planting a random sample of seeds one map at a time
+    Verify,
+}
+
+const VERIFY_SAMPLE_SIZE: u64 = 10_000;
+
+#[derive(Clone, Copy)]
+struct Mapping {
+    src: Interval<u64>,
+    dst_start: u64
+}
+
+// Converts numbers of the source category into the destination one
+struct Map {
+    source: String,
+    destination: String,
+    mappings: Vec<Mapping>,
+    // The mappings in the order they were given, before normalising. Empty for composed maps.
+    given: Vec<Mapping>
+}
+
+pub struct Almanac {
+    seeds: Vec<u32>,
+    // Keyed by source, as each category is converted by a single map
+    maps: HashMap<String, Map>,
+    // All the maps composed into one, from seed straight to location
+    seed_to_location: Map,
+    strategy: Part2Strategy,
+    // For the random sample of the verify strategy, drawn when verifying if not given
+    sample_seed: Option<u64>
+}
+
+impl Mapping {
+
+    fn parse(line: &str) -> Result<Mapping> {
+        let ns: Vec<u32> = line.try_parse_sequence()?;
+        if ns.len() != 3 {
+            return Err(Error::parse(line, "expected destination start, source start and length"));
+        }
+
+        Ok(Mapping {
+            src: Interval::with_length(ns[1] as u64, ns[2] as u64),
+            dst_start: ns[0] as u64,
+        })
+    }
+
+    fn convert(&self, num: u64) -> Option<u64> {
+        if self.src.contains(num) {
+            Some(self.dst_start + (num - self.src.start))
+        } else {
+            None
+        }
+    }
+
+    // Converts the part of the interval within the source, which is assumed to overlap it
+    fn convert_overlap(&self, interval: Interval<u64>) -> Interval<u64> {
+        let start = interval.start.max(self.src.start);
+        let end = interval.end.min(self.src.end);
+        Interval::new(start, end).translate(self.src.start, self.dst_start)
+    }
+
+}
+
+impl Map {
+
+    // Converts every number of the category to itself
+    fn identity(category: &str) -> Map {
+        Map { source: category.to_string(), destination: category.to_string(), mappings: Map::normalise(vec![]), given: vec![] }
+    }
+
+    fn parse(block: &str) -> Result<Map> {
+        let mut line_it = block.lines();
+        let header = line_it.next().ok_or("No header for map")?;
+        let (source, destination) = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| Error::parse(header, "expected a header like \"seed-to-soil map:\""))?
+            .try_split_once("-to-")?;
+
+        let mappings = line_it
+            .map(Mapping::parse)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Map {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            mappings: Map::normalise(mappings.clone()),
+            given: mappings
+        })
+    }
+
+    // Turns the mappings into pieces sorted by source and covering every number once, so that
+    // each number is converted by a single piece. Where sources overlap, the first mapping in the
+    // input wins, and numbers no mapping covers are converted to themselves.
+    fn normalise(mappings: Vec<Mapping>) -> Vec<Mapping> {
+        let mut covered = IntervalSet::new();
+        let mut pieces = vec![];
+        for mapping in mappings {
+            let src = IntervalSet::from(mapping.src).difference(&covered);
+            for &interval in src.intervals() {
+                pieces.push(Mapping { src: interval, dst_start: mapping.convert(interval.start).unwrap() });
+            }
+            covered = covered.union(&src);
+        }
+
+        let uncovered = IntervalSet::from(Interval::new(0, u64::MAX)).difference(&covered);
+        pieces.extend(uncovered.intervals().iter().map(|&src| Mapping { src, dst_start: src.start }));
+        pieces.sort_by_key(|m| m.src.start);
+        pieces
+    }
+
+    /// Converts with this map and then with the next one, as a single map.
+    fn then(&self, next: &Map) -> Map {
+        let mut mappings = vec![];
+        for mapping in &self.mappings {
+            // Each piece of the next map the destination overlaps becomes a piece on its own
+            let dst = Interval::with_length(mapping.dst_start, mapping.src.len());
+            for next_mapping in next.overlapping(dst) {
+                let start = dst.start.max(next_mapping.src.start);
+                let end = dst.end.min(next_mapping.src.end);
+                mappings.push(Mapping {
+                    src: Interval::new(start, end).translate(mapping.dst_start, mapping.src.start),
+                    dst_start: next_mapping.convert(start).unwrap()
+                });
+            }
+        }
+
+        Map { source: self.source.clone(), destination: next.destination.clone(), mappings, given: vec![] }
+    }
+
+    // The pieces whose source overlaps the given interval, in increasing order
+    fn overlapping(&self, interval: Interval<u64>) -> impl Iterator<Item = &Mapping> {
+        // The piece containing the start is the last one starting at or before it
+        let first = self.mappings.partition_point(|m| m.src.start <= interval.start) - 1;
+        self.mappings[first ..]
+            .iter()
+            .take_while(move |m| m.src.start < interval.end)
+    }
+
+    fn convert(&self, num: u64) -> u64 {
+        self.overlapping(Interval::with_length(num, 1))
+            .find_map(|m| m.convert(num))
+            .unwrap_or(num)
+    }
+
+    // Goes through the mappings as given, the first one whose source contains the number converting it
+    fn convert_as_given(&self, num: u64) -> u64 {
+        self.given
+            .iter()
+            .find_map(|m| m.convert(num))
+            .unwrap_or(num)
+    }
+
+    fn convert_range(&self, nums: &IntervalSet<u64>) -> IntervalSet<u64> {
+        nums.intervals()
+            .iter()
+            .flat_map(|&interval| self
+                .overlapping(interval)
+                .map(move |m| m.convert_overlap(interval)))
+            .collect()
+    }
+
+    // Every piece is gone through, as unlike their sources, destinations may overlap
+    fn unconvert_range(&self, nums: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.mappings
+            .iter()
+            .flat_map(|m| IntervalSet::from(Interval::with_length(m.dst_start, m.src.len()))
+                .intersection(nums)
+                .translate(m.dst_start, m.src.start)
+                .intervals()
+                .to_vec())
+            .collect()
+    }
+
+}
+
+impl Solution for Almanac {
+
+    fn parse(content: &str) -> Result<Almanac> {
+        let blocks = content.split_blocks();
+        let (seed_block, map_blocks) = blocks.split_first().ok_or("No seeds")?;
+
+        let seeds = seed_block.strip_label("seeds:")?.try_parse_sequence()?;
+
+        let mut sources = vec![];
+        let mut maps = HashMap::new();
+        for block in map_blocks {
+            let map = Map::parse(block)?;
+            if maps.contains_key(&map.source) {
+                return Err(Error::parse(block.lines().next().unwrap(), format!("{} is already converted by another map", map.source)));
+            }
+            sources.push(map.source.clone());
+            maps.insert(map.source.clone(), map);
+        }
+
+        // Following the maps from any category, coming back to one already on the way means there's a cycle
+        for source in &sources {
+            let mut path = vec![source.as_str()];
+            let mut curr = source;
+            while let Some(map) = maps.get(curr) {
+                curr = &map.destination;
+                let has_cycle = path.contains(&curr.as_str());
+                path.push(curr);
+                if has_cycle {
+                    return Err(format!("Categories cycle through {}", path.join(" -> ")).into());
+                }
+            }
+        }
+
+        let mut almanac = Almanac { seeds, maps, seed_to_location: Map::identity("seed"), strategy: Part2Strategy::default(), sample_seed: None };
+        almanac.seed_to_location = almanac.map_between("seed", "location")?;
+        Ok(almanac)
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.seeds
+            .iter()
+            .map(|seed| self.where_to_plant(*seed))
+            .min()
+            .ok_or("No seeds")?
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(match self.strategy {
+            Part2Strategy::EverySeed =>
+                self.seeds
+                    .chunks(2)
+                    .flat_map(|c| c[0] .. c[0] + c[1])
+                    .map(|seed| self.where_to_plant(seed))
+                    .min()
+                    .ok_or("No seeds")?,
+
+            Part2Strategy::EverySeedInParallel => {
+                let all_the_seeds: Vec<u32> = self.seeds
+                    .chunks(2)
+                    .flat_map(|c| c[0] .. c[0] + c[1])
+                    .collect();
+
+                all_the_seeds
+                    .par_iter()
+                    .map(|seed| self.where_to_plant(*seed))
+                    .min()
+                    .ok_or("No seeds")?
+            },
+
+            Part2Strategy::Ranges => self.lowest_location_of_seed_ranges()?,
+
+            Part2Strategy::Verify => {
+                let lowest_location = self.lowest_location_of_seed_ranges()?;
+                self.verify_lowest_location(lowest_location)?;
+                lowest_location
+            }
+        }.to_string())
+    }
+
+}
+
+impl Almanac {
+
+    pub fn with_strategy(self, strategy: Part2Strategy) -> Almanac {
+        Almanac { strategy, ..self }
+    }
+
+    /// Fixes the random sample of seeds planted by the verify strategy, to reproduce an earlier run.
+    pub fn with_sample_seed(self, sample_seed: u64) -> Almanac {
+        Almanac { sample_seed: Some(sample_seed), ..self }
+    }
+
+    fn seed_ranges(&self) -> IntervalSet<u64> {
+        self.seeds
+            .chunks(2)
+            .map(|c| Interval::with_length(c[0] as u64, c[1] as u64))
+            .collect()
+    }
+
+    fn lowest_location_of_seed_ranges(&self) -> Result<u64> {
+        Ok(self.where_to_plant_range(&self.seed_ranges())
+            .min()
+            .ok_or("No seeds or ranges are miscalculated")?)
+    }
+
+    // Plants seeds by going through the maps one at a time with their mappings as given, which doesn't
+    // rely on normalising or composing them nor on converting ranges. Each should end up within the ranges of locations and none lower than the
+    // given one, while the seeds going to that location according to the ranges should indeed get there.
+    fn verify_lowest_location(&self, lowest_location: u64) -> Result<()> {
+        let seeds = self.seed_ranges();
+        let locations = self.where_to_plant_range(&seeds);
+        let lowest_seeds = self.seeds_planted_at(&IntervalSet::from(Interval::with_length(lowest_location, 1)));
+        let lowest_seed = lowest_seeds
+            .intersection(&seeds)
+            .min()
+            .ok_or(format!("No seed is planted at the lowest location {lowest_location}"))?;
+
+        let sample_seed = self.sample_seed.unwrap_or_else(rand::random);
+        let mut rng = StdRng::seed_from_u64(sample_seed);
+        let sample = (0 .. VERIFY_SAMPLE_SIZE.min(seeds.len())).map(|_| seeds.nth(rng.gen_range(0 .. seeds.len())).unwrap());
+        let edges = seeds.intervals().iter().flat_map(|i| [i.start, i.end - 1]);
+        let mut num_checked = 0;
+        for seed in sample.chain(edges).chain([lowest_seed]) {
+            let location = self.where_to_plant_one_map_at_a_time(seed)?;
+            if !locations.contains(location) {
+                return Err(format!("Seed {seed} is planted at location {location}, which the ranges of locations miss (sample seed {sample_seed})").into());
+            }
+            if location < lowest_location || (seed == lowest_seed && location != lowest_location) {
+                return Err(format!("Seed {seed} is planted at location {location}, but ranges give {lowest_location} as the lowest (sample seed {sample_seed})").into());
+            }
+            num_checked += 1;
+        }
+
+        debug!(target: "day05", "Planting {num_checked} seeds one at a time agrees with {lowest_location} as the lowest location (sample seed {sample_seed})");
+        Ok(())
+    }
+
+    fn where_to_plant_one_map_at_a_time(&self, seed: u64) -> Result<u64> {
+        let mut category = "seed";
+        let mut num = seed;
+        while category != "location" {
+            let map = self.maps.get(category).ok_or(format!("No map converts {category}"))?;
+            num = map.convert_as_given(num);
+            category = &map.destination;
+        }
+        Ok(num)
+    }
+
+    pub fn where_to_plant(&self, seed: u32) -> u64 {
+        self.seed_to_location.convert(seed as u64)
+    }
+
+    pub fn where_to_plant_range(&self, seeds: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.seed_to_location.convert_range(seeds)
+    }
+
+    /// Converts a number of one category into another, going through whichever categories are in between.
+    pub fn convert(&self, from: &str, to: &str, num: u64) -> Result<u64> {
+        Ok(self.map_between(from, to)?.convert(num))
+    }
+
+    pub fn convert_range(&self, from: &str, to: &str, nums: &IntervalSet<u64>) -> Result<IntervalSet<u64>> {
+        Ok(self.map_between(from, to)?.convert_range(nums))
+    }
+
+    // Composes the maps from one category onwards until reaching the other, which can't
+    // go on forever as the categories were checked to have no cycles
+    fn map_between(&self, from: &str, to: &str) -> Result<Map> {
+        let mut composed = Map::identity(from);
+        while composed.destination != to {
+            let next = self.maps
+                .get(&composed.destination)
+                .ok_or_else(|| format!("No map converts {} on the way from {from} to {to}", composed.destination))?;
+            composed = composed.then(next);
+        }
+        Ok(composed)
+    }
+
+    /// All the seeds, planted or not, that would go to one of the given locations.
+    pub fn seeds_planted_at(&self, locations: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.seed_to_location.unconvert_range(locations)
+    }
+
+}
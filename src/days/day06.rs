@@ -0,0 +1,118 @@
+use num::integer::Roots;
+use crate::{Error, Result, Solution};
+use crate::str::StringOps;
+
+#[derive(Debug)]
+struct Race {
+    duration: u64,
+    record_distance: u64
+}
+
+// The sheet is read as several races in part 1 but as a single race
+// (ignoring the spaces between numbers) in part 2, so both are kept
+pub struct RaceSheet {
+    races: Vec<Race>,
+    single_race: Race
+}
+
+impl Solution for RaceSheet {
+
+    fn parse(content: &str) -> Result<RaceSheet> {
+        Ok(RaceSheet {
+            races: parse_races(content)?,
+            single_race: parse_single_race(content)?
+        })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.races
+            .iter()
+            .map(|r| r.number_of_ways_to_beat_record())
+            .product::<u64>()
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.single_race.number_of_ways_to_beat_record().to_string())
+    }
+
+}
+
+fn parse_races(content: &str) -> Result<Vec<Race>> {
+    let mut lines_it = content.lines();
+    
+    let times_str = lines_it.next().ok_or("No line for times")?;
+    let times: Vec<u64> = times_str.strip_label("Time:")?.try_parse_sequence()?;
+
+    let distances_str = lines_it.next().ok_or("No line for distances")?;
+    let distances: Vec<u64> = distances_str.strip_label("Distance:")?.try_parse_sequence()?;
+    if distances.len() != times.len() {
+        return Err(Error::parse(distances_str, format!("expected {} distances, one per time", times.len())));
+    }
+
+    let races = times
+        .iter()
+        .zip(distances)
+        .map(|(t, d)| Race { duration: *t, record_distance: d })
+        .collect();
+    
+    Ok(races)
+}
+
+fn parse_single_race(content: &str) -> Result<Race> {
+    let mut lines_it = content.lines();
+    
+    let time_str = lines_it.next().ok_or("No line for time")?.strip_label("Time:")?;
+    let duration = parse_ignoring_spaces(time_str)?;
+
+    let distance_str = lines_it.next().ok_or("No line for distance")?.strip_label("Distance:")?;
+    let record_distance = parse_ignoring_spaces(distance_str)?;
+
+    Ok(Race { duration, record_distance })
+}
+
+fn parse_ignoring_spaces(s: &str) -> Result<u64> {
+    s.replace(' ', "").try_parse().map_err(|err| err.at(s))
+}
+
+impl Race {
+
+    fn number_of_ways_to_beat_record(&self) -> u64 {
+        /* Essentially the distance travelled by the boat is given by v * (t - v)
+           where v is the velocity AND the time spent pressing the button
+           and t is the race duration.
+
+           So what we want to know is how many ways v * (t - v) > d, where d is
+           the current record distance. Applying the quadratic roots formula, we get
+           that our lowest and highest v is given by
+
+           min_v = (t - sqrt(t^2 - 4 * d)) / 2
+           max_v = (t + sqrt(t^2 - 4 * d)) / 2
+
+           Those are computed with integers to stay exact however big the race is,
+           which means the square root is rounded down. The distances are symmetric
+           around t / 2, so only the lowest v beating the record needs finding.
+        */
+        let t = self.duration as u128;
+        let d = self.record_distance as u128;
+        let beats_record = |v: u128| v * (t - v) > d;
+
+        let Some(discriminant) = (t * t).checked_sub(4 * d) else {
+            return 0;
+        };
+        // Off by at most one from the lowest v beating the record, also when the root is exact and ties it
+        let mut min_v = (t - discriminant.sqrt()) / 2;
+        while min_v <= t / 2 && !beats_record(min_v) {
+            min_v += 1;
+        }
+        while min_v > 0 && beats_record(min_v - 1) {
+            min_v -= 1;
+        }
+
+        if min_v > t / 2 {
+            return 0;
+        }
+        (t - 2 * min_v + 1) as u64
+    }
+
+}
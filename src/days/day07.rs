@@ -1,6 +1,5 @@
-use std::fs;
-use adventofcode2023::{Result, Part};
-use adventofcode2023::str::StringOps;
+use crate::{Result, Solution};
+use crate::str::StringOps;
 
 #[derive(Debug)]
 enum HandType {
@@ -13,53 +12,57 @@ enum HandType {
     FiveOfAKind = 7,
 }
 
-struct Hand<'a> {
-    cards: &'a str,
+struct Hand {
+    cards: String,
     bid: u32
 }
 
-fn main() {
-    println!("example (part1): {:?}", solve("inputs/day07_example", Part::One));
-    println!("input (part1): {:?}", solve("inputs/day07", Part::One));
-    println!("example (part2): {:?}", solve("inputs/day07_example", Part::Two));
-    println!("input (part2): {:?}", solve("inputs/day07", Part::Two));
+pub struct CamelCardsGame {
+    hands: Vec<Hand>
 }
 
-fn solve(path: &str, part: Part) -> Result<u32> {
-    let content = fs::read_to_string(path)?;
-    let mut hands: Vec<_> = content
-        .lines()
-        .map(|l| Hand::parse(&l).unwrap())
-        .collect();
-
-    Ok(match part {
-        Part::One => {
-            hands.sort_by_cached_key(|h| h.strength(/*use_joker_rule*/false));
-            hands
-                .iter()
-                .enumerate()
-                .map(|(idx, h)| (idx as u32 + 1) * h.bid)
-                .sum()
-        },
-        
-        Part::Two => {
-            hands.sort_by_cached_key(|h| h.strength(/*use_joker_rule*/true));
-            hands
-                .iter()
-                .enumerate()
-                .map(|(idx, h)| (idx as u32 + 1) * h.bid)
-                .sum()
-        }
-    })
+impl Solution for CamelCardsGame {
+
+    fn parse(content: &str) -> Result<CamelCardsGame> {
+        let hands = content
+            .lines()
+            .map(Hand::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(CamelCardsGame { hands })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.total_winnings(/*use_joker_rule*/false).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.total_winnings(/*use_joker_rule*/true).to_string())
+    }
+
+}
+
+impl CamelCardsGame {
+
+    fn total_winnings(&self, use_joker_rule: bool) -> u32 {
+        let mut hands: Vec<_> = self.hands.iter().collect();
+        hands.sort_by_cached_key(|h| h.strength(use_joker_rule));
+        hands
+            .iter()
+            .enumerate()
+            .map(|(idx, h)| (idx as u32 + 1) * h.bid)
+            .sum()
+    }
+
 }
 
-impl Hand<'_> {
+impl Hand {
 
     fn parse(line: &str) -> Result<Hand> {
         let (cards, bid_str) = line.try_split_once(" ")?;
-        return Ok(Hand {
-            cards,
-            bid: bid_str.parse()?
+        Ok(Hand {
+            cards: cards.to_string(),
+            bid: bid_str.try_parse()?
         })
     }
 
@@ -79,8 +82,8 @@ impl Hand<'_> {
         let mut num_pairs: u8 = 0;
         let mut num_jokers = (curr_label == 'J') as u8; 
 
-        for i in 1 .. sorted_cards.len() {
-            if sorted_cards[i] == curr_label {
+        for &card in &sorted_cards[1 ..] {
+            if card == curr_label {
                 curr_group_size += 1;
             } else {
                 if curr_group_size == 2 {
@@ -89,7 +92,7 @@ impl Hand<'_> {
 
                 num_distinct_labels += 1;
                 curr_group_size = 1;
-                curr_label = sorted_cards[i];
+                curr_label = card;
             }
 
             if curr_label == 'J' {
@@ -107,7 +110,7 @@ impl Hand<'_> {
         }
 
         // Based on the number of labels and number of pairs we can now classify in types
-        return self.classify_without_jokers(num_pairs, num_distinct_labels);
+        self.classify_without_jokers(num_pairs, num_distinct_labels)
     }
 
     fn classify_with_jokers(&self, num_pairs: u8, num_distinct_labels: u8, num_jokers: u8) -> HandType {
@@ -186,7 +189,7 @@ impl Hand<'_> {
         let mut strength_str = type_n.to_string();
         strength_str.push_str(cards_as_strengths.as_str());
         
-        return strength_str
+        strength_str
     }
 
 }
\ No newline at end of file
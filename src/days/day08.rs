@@ -1,81 +1,92 @@
 use std::collections::HashMap;
-use std::fs;
+use log::debug;
 use num::integer::lcm;
-use adventofcode2023::{Result, Part};
-use adventofcode2023::str::StringOps;
-
-enum Direction {
-    Left,
-    Right
+use crate::{Error, Result, Solution, char_enum};
+use crate::dot::{Digraph, ToDot};
+use crate::str::StringOps;
+
+char_enum! {
+    enum Direction {
+        Left = b'L',
+        Right = b'R',
+    }
 }
 
 type Node<'a> = &'a str;
 
-struct Documents<'a> {
+pub struct Documents {
     instructions: Vec<Direction>,
-    network: HashMap<Node<'a>, (Node<'a>, Node<'a>)>
-}
-
-fn main() {
-    println!("example1 (part1): {:?}", solve("inputs/day08_example1", Part::One));
-    println!("example2 (part1): {:?}", solve("inputs/day08_example2", Part::One));
-    println!("input (part1): {:?}", solve("inputs/day08", Part::One));
-    println!("example3 (part2): {:?}", solve("inputs/day08_example3", Part::Two));
-    println!("input (part2): {:?}", solve("inputs/day08", Part::Two));
-}
-
-fn solve(path: &str, part: Part) -> Result<u128> {
-    let content = fs::read_to_string(path)?;
-    let documents = Documents::parse(&content)?;
-
-    Ok(match part {
-        Part::One =>
-            documents.count_steps("AAA", |n| n == "ZZZ") as u128,
-        
-        Part::Two => {
-            // Simplified solution since the input ends up not having multiple paths, etc.
-            let cycle_steps: Vec<_> = documents.network
-                .keys()
-                .filter(|n| n.ends_with('A'))
-                .map(|n| documents.count_steps(*n, |curr_n| curr_n.ends_with('Z')))
-                .collect();
-
-            cycle_steps.iter().fold(1 as u128, |a, b| lcm(a, *b as u128))
-        }
-    })
+    network: HashMap<String, (String, String)>
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 struct CycleStats(usize, u32, u32);
 
-impl<'a> Documents<'a> {
+impl Solution for Documents {
 
     fn parse(content: &str) -> Result<Documents> {
         let mut line_it = content.lines();
         let instr_str = line_it.next().ok_or("No instructions")?;
-        let instructions = instr_str
-            .chars()
-            .map(|c| if c == 'L' { Direction::Left } else { Direction::Right })
-            .collect();
+        let instructions: Vec<_> = instr_str.try_parse_chars()?;
+        if instructions.is_empty() {
+            return Err(Error::parse(instr_str, "expected instructions like LLR"));
+        }
 
         line_it.next(); // skip empty line
 
         let mut network = HashMap::new();
+        let mut destinations = vec![];
         for line in line_it {
             let (node, edges_str) = line.try_split_once(" = ")?;
-            network.insert(node, (&edges_str[1 .. 4], &edges_str[6 .. 9]));
+            let (left, right) = edges_str
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .ok_or_else(|| Error::parse(edges_str, "expected nodes like (BBB, CCC)"))?
+                .try_split_once(", ")?;
+
+            if network.insert(node.to_string(), (left.to_string(), right.to_string())).is_some() {
+                return Err(Error::parse(node, "duplicate node"));
+            }
+            destinations.extend([left, right]);
+        }
+
+        if let Some(unknown) = destinations.into_iter().find(|n| !network.contains_key(*n)) {
+            return Err(Error::parse(unknown, "unknown node"));
         }
 
         Ok(Documents { instructions, network })
     }
 
-    fn count_steps(&self, src: Node, is_end: impl Fn(Node) -> bool) -> u32 {
+    fn part1(&self) -> Result<String> {
+        if !self.network.contains_key("AAA") {
+            return Err("No AAA node to start from".into());
+        }
+        Ok(self.count_steps("AAA", |n| n == "ZZZ").to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        // Simplified solution since the input ends up not having multiple paths, etc.
+        let cycle_steps: Vec<_> = self.network
+            .keys()
+            .filter(|n| n.ends_with('A'))
+            .map(|n| self.count_steps(n, |curr_n| curr_n.ends_with('Z')))
+            .collect();
+
+        Ok(cycle_steps.iter().fold(1_u128, |a, b| lcm(a, *b as u128)).to_string())
+    }
+
+}
+
+impl Documents {
+
+    fn count_steps<'a>(&'a self, src: Node<'a>, is_end: impl Fn(Node) -> bool) -> u32 {
         let mut curr_node = src;
         let mut num_steps = 0;
         let mut instr_idx = 0;
 
         while !is_end(curr_node) {
-            let (left, right) = self.network[curr_node];
+            let (left, right) = &self.network[curr_node];
             curr_node = match self.instructions[instr_idx] {
                 Direction::Left => left,
                 Direction::Right => right
@@ -84,7 +95,7 @@ impl<'a> Documents<'a> {
             num_steps += 1;
         }
 
-        return num_steps;
+        num_steps
     }
 
     // The general problem may include multiple paths to ends, some of which enter cycles
@@ -95,7 +106,7 @@ impl<'a> Documents<'a> {
     // In practice the input has none of that complexity, just a single path that cycles
     // consistently.
     #[allow(dead_code)]
-    fn get_ending_stats(&self, src: Node<'a>, dst_suffix: &str) -> Vec<CycleStats> {
+    fn get_ending_stats<'a>(&'a self, src: Node<'a>, dst_suffix: &str) -> Vec<CycleStats> {
         let mut end_path_lens: HashMap<(Node, usize, bool), u32> = HashMap::new();
         let mut end_path_stats: Vec<CycleStats> = vec![];
 
@@ -118,7 +129,7 @@ impl<'a> Documents<'a> {
                 }
             }
 
-            let (left, right) = self.network[curr_node];
+            let (left, right) = &self.network[curr_node];
             curr_node = match self.instructions[instr_idx] {
                 Direction::Left => left,
                 Direction::Right => right
@@ -135,7 +146,7 @@ impl<'a> Documents<'a> {
         let mut common_cycles = vec![vec![]; self.instructions.len()];
         for node in starter_nodes.as_slice() {
             let stats = self.get_ending_stats(node, "Z");
-            println!("{} -> {:?}", node, stats);
+            debug!(target: "day08::cycles", "{node} -> {stats:?}");
 
             // Store all cycles that are hit in the same instructions
             for s in stats {
@@ -144,12 +155,37 @@ impl<'a> Documents<'a> {
         }
 
         // Keep only cycles that are common to all starter nodes
-        return common_cycles
+        common_cycles
             .as_slice()
             .iter()
             .filter(|c| c.len() == starter_nodes.len())
-            .map(|c| c.clone())
-            .collect();
+            .cloned()
+            .collect()
+    }
+
+}
+
+impl ToDot for Documents {
+
+    // Starting nodes are drawn as houses and ending ones with a double circle
+    fn to_dot(&self) -> Digraph {
+        let mut graph = Digraph::new("network");
+        let mut nodes: Vec<_> = self.network.iter().collect();
+        nodes.sort();
+
+        for (node, (left, right)) in nodes {
+            let shape = if node.ends_with('A') {
+                "house"
+            } else if node.ends_with('Z') {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            graph.node(node, node, shape);
+            graph.edge(node, left, Some("L"));
+            graph.edge(node, right, Some("R"));
+        }
+        graph
     }
 
-}
\ No newline at end of file
+}
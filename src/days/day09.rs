@@ -0,0 +1,52 @@
+use crate::{Result, Solution};
+use crate::str::StringOps;
+
+pub struct OasisReport {
+    histories: Vec<Vec<i32>>
+}
+
+impl Solution for OasisReport {
+
+    fn parse(content: &str) -> Result<OasisReport> {
+        let histories = content
+            .lines()
+            .map(|l| l.try_parse_sequence())
+            .collect::<Result<_>>()?;
+
+        Ok(OasisReport { histories })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.histories
+            .iter()
+            .map(|h| predict_next(h))
+            .sum::<i32>()
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.histories
+            .iter()
+            .map(|h| {
+                let reversed_h: Vec<_> = h.iter().rev().copied().collect();
+                predict_next(&reversed_h)
+            })
+            .sum::<i32>()
+            .to_string())
+    }
+
+}
+
+fn predict_next(seq: &[i32]) -> i32 {
+    let inner_seq: Vec<_> = seq
+        .iter()
+        .zip(seq.iter().skip(1))
+        .map(|(a, b)| b - a)
+        .collect();
+
+    if inner_seq.iter().all(|n| *n == 0) {
+        seq[0]
+    } else {
+        seq.last().unwrap() + predict_next(&inner_seq)
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::LinkedList;
+use log::{Level, log_enabled, trace, debug};
+use crate::{Error, Result, Solution, char_enum};
+use crate::grid::Grid;
+
+char_enum! {
+    #[derive(Copy, Clone, PartialEq)]
+    enum Tile {
+        NorthAndSouth = b'|',
+        NorthAndEast = b'L',
+        NorthAndWest = b'J',
+        WestAndEast = b'-',
+        SouthAndWest = b'7',
+        SouthAndEast = b'F',
+        Ground = b'.',
+        Start = b'S',
+    }
+}
+
+const PIPES: [Tile; 6] = [
+    Tile::NorthAndSouth, Tile::NorthAndEast, Tile::NorthAndWest,
+    Tile::WestAndEast, Tile::SouthAndWest, Tile::SouthAndEast
+];
+
+pub struct PipeMap {
+    tiles: Grid<Tile>
+}
+
+#[derive(PartialEq, Clone, Copy)]
+struct Position {
+    r: usize,
+    c: usize
+}
+
+char_enum! {
+    #[derive(PartialEq, Clone, Copy)]
+    enum TileState {
+        Unknown = b'?',
+        Inside = b'I',
+        Outside = b'O',
+        Boundary = b'B',
+    }
+}
+
+impl Solution for PipeMap {
+
+    fn parse(content: &str) -> Result<PipeMap> {
+        let tiles = Grid::parse(content, Tile::try_from)?;
+
+        Ok(PipeMap { tiles })
+    }
+
+    fn part1(&self) -> Result<String> {
+        let pipe_loop = self.find_loop()?;
+        Ok((pipe_loop.len() / 2).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let pipe_loop = self.find_loop()?;
+        Ok(self.find_area_within_loop(pipe_loop.into_iter())?.to_string())
+    }
+
+}
+
+impl PipeMap {
+
+    fn find_start(&self) -> Option<Position> {
+        self.tiles
+            .position(|t| *t == Tile::Start)
+            .map(|(r, c)| Position { r, c })
+    }
+
+    fn infer_tile(&self, pos: Position) -> Option<Tile> {
+        // The start is whichever pipe has both its ends on the tiles connected to it
+        let connections = self.find_connected_tiles(pos);
+        PIPES
+            .into_iter()
+            .find(|t| t.ends(pos).is_some_and(|ends| ends.iter().all(|e| connections.contains(e))))
+    }
+
+    fn find_connected_tiles(&self, pos: Position) -> Vec<Position> {
+        let Position { r, c } = pos; 
+        let mut connected = vec![];
+        
+        if r > 0 && [Tile::SouthAndEast, Tile::NorthAndSouth, Tile::SouthAndWest].contains(&self.tiles[r - 1][c]) {
+            connected.push(Position { r: r - 1, c });
+        }
+
+        if c > 0 && [Tile::NorthAndEast, Tile::SouthAndEast, Tile::WestAndEast].contains(&self.tiles[r][c - 1]) {
+            connected.push(Position { r, c: c - 1 })
+        }
+
+        if r + 1 < self.tiles.num_rows() && [Tile::NorthAndEast, Tile::NorthAndSouth, Tile::NorthAndWest].contains(&self.tiles[r + 1][c]) {
+            connected.push(Position { r: r + 1, c })
+        }
+
+        if c + 1 < self.tiles.num_cols() && [Tile::WestAndEast, Tile::SouthAndWest, Tile::NorthAndWest].contains(&self.tiles[r][c + 1]) {
+            connected.push(Position { r, c: c + 1 })
+        }
+
+        connected
+    }
+
+    fn next(&self, prev: Position, curr: Position) -> Option<Position> {
+        // Only follow the pipe if we came in through one of its ends, and it leads into another pipe
+        // that connects back to it
+        let [e1, e2] = self.tiles.get(curr.r, curr.c)?.ends(curr)?;
+        let next = if e1 == prev {
+            e2
+        } else if e2 == prev {
+            e1
+        } else {
+            return None;
+        };
+        self.tiles.get(next.r, next.c)?
+            .ends(next)?
+            .contains(&curr)
+            .then_some(next)
+    }
+
+    fn find_loop(&self) -> Result<LinkedList<Position>> {
+        let start_pos = self.find_start().ok_or("No start tile S found")?;
+        let connections = self.find_connected_tiles(start_pos);
+        if connections.len() != 2 {
+            return Err(format!("Expected S at {start_pos:?} to connect to 2 pipes, found {}", connections.len()).into());
+        }
+
+        let mut pipe_loop = LinkedList::from([start_pos]); 
+        let mut curr_frontier: Vec<_> = connections.iter().map(|p| (start_pos, *p)).collect();
+        loop {
+            trace!(target: "day10::loop", "{curr_frontier:?}");
+            curr_frontier.dedup_by(|(_, p1), (_, p2)| p1 == p2);
+            pipe_loop.push_back(curr_frontier[0].1);
+            
+            if curr_frontier.len() < 2 {
+                break;
+            }
+            pipe_loop.push_front(curr_frontier[1].1);
+
+            for (prev, curr) in curr_frontier.as_mut_slice() {
+                let new_curr = self.next(*prev, *curr)
+                    .ok_or_else(|| Error::from(format!("Pipe loop is broken at {curr:?}")))?;
+                *prev = *curr;
+                *curr = new_curr;
+            }
+        }
+
+        Ok(pipe_loop)
+    }
+
+    fn find_area_within_loop(&self, pipe_loop: impl Iterator<Item = Position>) -> Result<usize> {
+        // Initialize a matrix with all states set to Unknown except for the Boundaries
+        let num_rows = self.tiles.num_rows();
+        let num_cols = self.tiles.num_cols();
+        let mut states = Grid::new(num_rows, num_cols, TileState::Unknown);
+        for Position { r, c } in pipe_loop {
+            states[r][c] = TileState::Boundary;
+        }
+
+        // Initialize a slightly bigger matrix to store if the corners of `states` are outside or inside.
+        // We know the outer corners all around are necessarily outside, so those get marked right away.
+        let mut outside = Grid::new(num_rows + 1, num_cols + 1, None);
+        for r in 0 ..= num_rows {
+            outside[r][0] = Some(true);
+            outside[r][num_cols] = Some(true);
+        }
+        for c in 0 ..= num_cols {
+            outside[0][c] = Some(true);
+            outside[num_rows][c] = Some(true);
+        }
+
+        // For each map position, keep updating the southwest and southeast corners depending on the shape
+        // of the boundary. At the same time, use the northeast corner info to understand if Unknown positions
+        // should be marked as outside or inside.
+        for r in 0 .. num_rows {
+            let t_row = &self.tiles[r];
+
+            if states[r][0] != TileState::Boundary {
+                outside[r + 1][0] = Some(true);
+                outside[r + 1][1] = Some(true);
+            }
+
+            for c in 0 .. num_cols {
+                let is_northwest_outside = outside[r][c];
+
+                if states[r][c] == TileState::Boundary {
+                    let tile_to_match = if t_row[c] == Tile::Start {
+                        self.infer_tile(Position { r, c }).ok_or("Can't tell which pipe S is")?
+                    } else {
+                        t_row[c]
+                    };
+                    match tile_to_match {
+                        Tile::NorthAndEast => {
+                            outside[r + 1][c] = is_northwest_outside;
+                            outside[r + 1][c + 1] = is_northwest_outside;
+                        },
+                        Tile::NorthAndSouth => {
+                            outside[r + 1][c] = is_northwest_outside;
+                            outside[r + 1][c + 1] = is_northwest_outside.map(|o| !o);
+                        },
+                        Tile::NorthAndWest => {
+                            outside[r + 1][c] = is_northwest_outside.map(|o| !o);
+                            outside[r + 1][c + 1] = is_northwest_outside.map(|o| !o);
+                        },
+                        Tile::WestAndEast => {
+                            outside[r + 1][c] = is_northwest_outside.map(|o| !o);
+                            outside[r + 1][c + 1] = is_northwest_outside.map(|o| !o);
+                        },
+                        Tile::SouthAndEast => {
+                            outside[r + 1][c] = is_northwest_outside;
+                            outside[r + 1][c + 1] = is_northwest_outside.map(|o| !o);
+                        },
+                        Tile::SouthAndWest => {
+                            outside[r + 1][c] = is_northwest_outside.map(|o| !o);
+                            outside[r + 1][c + 1] = is_northwest_outside;
+                        },
+                        Tile::Ground => {
+                            outside[r + 1][c] = is_northwest_outside;
+                            outside[r + 1][c + 1] = is_northwest_outside;
+                        },
+                        Tile::Start => unreachable!("S was replaced by the pipe under it")
+                    }
+                } else if states[r][c] == TileState::Unknown {
+                    states[r][c] = if outside[r][c].unwrap() {
+                        TileState::Outside
+                    } else {
+                        TileState::Inside
+                    };
+                    outside[r + 1][c] = outside[r][c];
+                    outside[r + 1][c + 1] = outside[r][c];
+                }
+            }
+        }
+
+        if log_enabled!(target: "day10::area", Level::Debug) {
+            let corners = outside.map(|o| match o {
+                None => '?',
+                Some(true) => 'T',
+                Some(false) => 'F',
+            });
+            debug!(target: "day10::area", "Outside corners:\n{corners}");
+
+            let mut tiles = self.tiles.map(|t| t.to_string());
+            for ((r, c), s) in states.cells() {
+                if *s != TileState::Boundary {
+                    tiles[r][c] = s.to_string();
+                }
+            }
+            debug!(target: "day10::area", "Tile states:\n{tiles}");
+        }
+
+        Ok(states
+            .iter()
+            .filter(|s| **s == TileState::Inside)
+            .count())
+    }
+
+}
+
+impl Tile {
+
+    // The two positions a pipe at `pos` leads to, or None for anything that isn't a pipe
+    fn ends(self, pos: Position) -> Option<[Position; 2]> {
+        let Position { r, c } = pos;
+        let north = || Some(Position { r: r.checked_sub(1)?, c });
+        let south = || Some(Position { r: r + 1, c });
+        let west = || Some(Position { r, c: c.checked_sub(1)? });
+        let east = || Some(Position { r, c: c + 1 });
+        match self {
+            Tile::NorthAndSouth => Some([north()?, south()?]),
+            Tile::NorthAndEast => Some([north()?, east()?]),
+            Tile::NorthAndWest => Some([north()?, west()?]),
+            Tile::WestAndEast => Some([west()?, east()?]),
+            Tile::SouthAndWest => Some([south()?, west()?]),
+            Tile::SouthAndEast => Some([south()?, east()?]),
+            Tile::Ground | Tile::Start => None
+        }
+    }
+
+}
+
+impl core::fmt::Debug for PipeMap {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.tiles)
+    }
+}
+
+impl core::fmt::Debug for Position {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.r, self.c)
+    }
+}
+
+impl core::fmt::Debug for Tile {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+
+}
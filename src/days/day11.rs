@@ -0,0 +1,81 @@
+use log::trace;
+use crate::{Result, Solution};
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug)]
+struct Coord {
+    x: usize,
+    y: usize
+}
+
+#[derive(Clone, Debug)]
+pub struct SpaceImage {
+    galaxy_coords: Vec<Coord>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>
+}
+
+impl Solution for SpaceImage {
+
+    fn parse(content: &str) -> Result<SpaceImage> {
+        let image = Grid::parse(content, Ok)?;
+
+        let galaxy_coords = image
+            .cells()
+            .filter(|(_, &c)| c == '#')
+            .map(|((y, x), _)| Coord { x, y })
+            .collect();
+
+        let empty_rows = (0 .. image.num_rows())
+            .filter(|&y| image.row(y).iter().all(|&c| c != '#'))
+            .collect();
+
+        let empty_cols = (0 .. image.num_cols())
+            .filter(|&x| image.col(x).all(|&c| c != '#'))
+            .collect();
+
+        Ok(SpaceImage { galaxy_coords, empty_rows, empty_cols })
+    }
+
+    fn part1(&self) -> Result<String> {
+        let mut image = self.clone();
+        image.expand_universe(2);
+        Ok(calculate_total_distances(&image).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let mut image = self.clone();
+        image.expand_universe(1_000_000);
+        Ok(calculate_total_distances(&image).to_string())
+    }
+
+}
+
+impl SpaceImage {
+
+    fn expand_universe(&mut self, scale: u32) {
+        for c in self.galaxy_coords.as_mut_slice() {
+            c.x += (scale as usize - 1) * self.empty_cols.iter().take_while(|&&x| x < c.x).count();
+            c.y += (scale as usize - 1) * self.empty_rows.iter().take_while(|&&y| y < c.y).count();
+        }
+    }
+
+    fn calculate_distance(&self, g1: Coord, g2: Coord) -> u32 {
+        let dx = g1.x.abs_diff(g2.x);
+        let dy = g1.y.abs_diff(g2.y);
+
+        (dx + dy) as u32
+    }
+
+}
+
+fn calculate_total_distances(image: &SpaceImage) -> u64 {
+    trace!(target: "day11", "{image:?}");
+    let mut total = 0_u64;
+    for (i, &g1) in image.galaxy_coords.iter().enumerate() {
+        for &g2 in &image.galaxy_coords[i+1 ..] {
+            total += image.calculate_distance(g1, g2) as u64;
+        }
+    }
+    total
+}
\ No newline at end of file
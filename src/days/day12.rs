@@ -1,15 +1,14 @@
 use std::collections::HashMap;
-use std::time::Instant;
-use std::fs;
-use adventofcode2023::{Result, Part};
-use adventofcode2023::str::StringOps;
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-#[repr(u8)]
-enum Condition {
-    Operational = b'.',
-    Damaged = b'#',
-    Unknown = b'?',
+use crate::{Result, Solution, char_enum};
+use crate::str::StringOps;
+
+char_enum! {
+    #[derive(PartialEq, Eq, Hash, Clone, Copy)]
+    enum Condition {
+        Operational = b'.',
+        Damaged = b'#',
+        Unknown = b'?',
+    }
 }
 
 struct HotSpringRow {
@@ -17,43 +16,31 @@ struct HotSpringRow {
     spec: Vec<u32>
 }
 
-struct Field {
+pub struct Field {
     springs: Vec<HotSpringRow>
 }
 
-fn main() {
-    println!("example (part1): {:?}", solve("inputs/day12_example", Part::One));
-    let time_part1 = Instant::now();
-    println!("input (part1): {:?} ({} µs)", solve("inputs/day12", Part::One), time_part1.elapsed().as_micros());
-    println!("example (part2): {:?}", solve("inputs/day12_example", Part::Two));
-    let time_part2 = Instant::now();
-    println!("input (part2): {:?} ({} µs)", solve("inputs/day12", Part::Two), time_part2.elapsed().as_micros());
-}
-
-fn solve(path: &str, part: Part) -> Result<u64> {
-    let content = fs::read_to_string(path)?;
-    let field = Field::parse(&content);
+impl Solution for Field {
 
-    Ok(match part {
-        Part::One =>
-            field.springs
-                .iter()
-                .map(|s| s.count_arrangements())
-                .sum(),
-        
-        Part::Two =>
-            field.springs
-                .iter()
-                .map(|s| s.unfold().count_arrangements())
-                .sum()
-    })
-}
+    fn parse(content: &str) -> Result<Field> {
+        let springs = content.lines().map(HotSpringRow::parse).collect::<Result<_>>()?;
+        Ok(Field { springs })
+    }
 
-impl Field {
+    fn part1(&self) -> Result<String> {
+        Ok(self.springs
+            .iter()
+            .map(|s| s.count_arrangements())
+            .sum::<u64>()
+            .to_string())
+    }
 
-    fn parse(content: &str) -> Field {
-        let springs = content.lines().map(|l| HotSpringRow::parse(&l).unwrap()).collect();
-        Field { springs }
+    fn part2(&self) -> Result<String> {
+        Ok(self.springs
+            .iter()
+            .map(|s| s.unfold().count_arrangements())
+            .sum::<u64>()
+            .to_string())
     }
 
 }
@@ -63,8 +50,8 @@ impl HotSpringRow {
     fn parse(line: &str) -> Result<HotSpringRow> {
         let (cond_str, spec_str) = line.try_split_once(" ")?;
         Ok(HotSpringRow {
-            conditions: cond_str.chars().map(|c| Condition::parse(c)).collect(),
-            spec: spec_str.split(',').map(|ns| ns.parse().unwrap()).collect()
+            conditions: cond_str.try_parse_chars()?,
+            spec: spec_str.try_parse_list(",")?
         })
     }
 
@@ -89,25 +76,13 @@ impl HotSpringRow {
 impl core::fmt::Debug for HotSpringRow {
 
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let cond_str = String::from_iter(self.conditions.iter().map(|c| c.to_char()));
+        let cond_str = String::from_iter(self.conditions.iter().map(|c| c.to_string()));
         writeln!(f, "{} {:?}", cond_str, self.spec)?;
         Ok(())
     }
 
 }
 
-impl Condition {
-
-    fn parse(c: char) -> Condition {
-        unsafe { std::mem::transmute(c as u8) }
-    }
-    
-    fn to_char(&self) -> char {
-        char::from(*self as u8)
-    }
-
-}
-
 fn count_arrangements<'a, 'b>(
     mut conds: &'a [Condition],
     spec: &'b [u32],
@@ -165,5 +140,5 @@ fn count_arrangements<'a, 'b>(
         num_arrangements += *cache.entry((&conds[1..], spec)).or_insert(total);
     }
     
-    return num_arrangements
+    num_arrangements
 }
@@ -0,0 +1,128 @@
+use log::debug;
+use crate::{Result, Solution, char_enum};
+use crate::grid::Grid;
+use crate::str::StringOps;
+
+char_enum! {
+    #[derive(PartialEq, Clone, Copy)]
+    enum Tile {
+        Ash = b'.',
+        Rocks = b'#',
+    }
+}
+
+struct Pattern {
+    tiles: Grid<Tile>
+}
+
+pub struct Notes {
+    patterns: Vec<Pattern>
+}
+
+impl Solution for Notes {
+
+    fn parse(content: &str) -> Result<Notes> {
+        let patterns = content
+            .split_blocks()
+            .into_iter()
+            .map(Pattern::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(Notes { patterns })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(summarize_mirrors(&self.patterns, false).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(summarize_mirrors(&self.patterns, true).to_string())
+    }
+
+}
+
+fn summarize_mirrors(patterns: &[Pattern], consider_smudges: bool) -> u32 {
+    patterns
+        .iter()
+        .map(|p| {
+            if let Some(n_rows) = p.get_row_mirror_span(consider_smudges) {
+                debug!(target: "day13::mirrors", "Rows: {n_rows}");
+                100 * n_rows
+            } else {
+                let n_cols = p.transpose().get_row_mirror_span(consider_smudges).unwrap();
+                debug!(target: "day13::mirrors", "Cols: {n_cols}");
+                n_cols
+            }
+        })
+        .sum()
+}
+
+impl Pattern {
+
+    fn parse(block: &str) -> Result<Pattern> {
+        Ok(Pattern { tiles: Grid::parse(block, Tile::try_from)? })
+    }
+
+    fn get_row_mirror_span(&self, consider_smudges: bool) -> Option<u32> {
+        for r in 0 .. self.tiles.num_rows() {
+            if self.is_mirror_row(r, consider_smudges) {
+                return Some(r as u32 + 1)
+            }
+        }
+
+        None
+    }
+
+    fn is_mirror_row(&self, r: usize, consider_smudges: bool) -> bool {
+        let mut up_r = r as i32;
+        let mut down_r = r + 1;
+        let mut has_found_smudge = false;
+
+        while up_r >= 0 && down_r < self.tiles.num_rows() {
+            if self.tiles[up_r as usize] == self.tiles[down_r] {
+                up_r -= 1;
+                down_r += 1;
+            } else {
+                if consider_smudges && !has_found_smudge {
+                    // If we haven't found the smudge, check if the only difference is a single tile
+                    // and if so continue as if we had succeeded, simulating the scenario where this is the smudge
+                    let mut diff_cols_it = self.tiles[up_r as usize]
+                        .iter()
+                        .zip(&self.tiles[down_r])
+                        .enumerate()
+                        .filter(|(_, (up_t, down_t))| **up_t != **down_t);
+
+                    let first_diff_col = diff_cols_it.next().unwrap();
+                    if diff_cols_it.next().is_none() {
+                        // then it's unique
+                        debug!(target: "day13::smudges", "Found mirror rows with unique difference: {} and {} at {}", up_r, down_r, first_diff_col.0);
+                        has_found_smudge = true;
+                        up_r -= 1;
+                        down_r += 1;
+                        continue;
+                    }
+                }
+
+                return false;
+            }
+        }
+
+        // It's a mirror row if we tested anything at all
+        // and (iff looking for smudges) it has a smudge
+        up_r + 1 != down_r as i32
+            && consider_smudges == has_found_smudge
+    }
+
+    fn transpose(&self) -> Pattern {
+        Pattern { tiles: self.tiles.transpose() }
+    }
+
+}
+
+impl core::fmt::Debug for Tile {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+
+}
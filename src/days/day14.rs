@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use log::{debug, trace};
+use crate::{Result, Solution, char_enum};
+use crate::grid::Grid;
+
+char_enum! {
+    #[derive(PartialEq, Clone, Copy, Hash, Eq)]
+    enum Tile {
+        Empty = b'.',
+        RoundRock = b'O',
+        CubeRock = b'#'
+    }
+}
+
+#[derive(Clone)]
+pub struct Platform {
+    tiles: Grid<Tile>
+}
+
+impl Solution for Platform {
+
+    fn parse(content: &str) -> Result<Platform> {
+        let tiles = Grid::parse(content, Tile::try_from)?;
+        
+        Ok(Platform { tiles })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.tilt_north_and_calculate_load().to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let mut platform = self.clone();
+        let mut cache = HashMap::new();
+        trace!(target: "day14::tilts", "Initial:\n{platform:?}");
+        let mut i = 1;
+        let mut limit = 1_000_000_000;
+        let mut found_repetition = false;
+
+        while i <= limit {
+            platform.tilt_north();
+            trace!(target: "day14::tilts", "North {i}:\n{platform:?}");
+            platform.tilt_west();
+            trace!(target: "day14::tilts", "West {i}:\n{platform:?}");
+            platform.tilt_south();
+            trace!(target: "day14::tilts", "South {i}:\n{platform:?}");
+            platform.tilt_east();
+            trace!(target: "day14::tilts", "East {i}:\n{platform:?}");
+
+            if !found_repetition {
+                if let Some(prev_i) = cache.get(&platform.tiles) {
+                    found_repetition = true;
+                    debug!(target: "day14::cycles", "Found repeated state from cycle {prev_i} at cycle {i}");
+                    let repeat_period = i - prev_i;
+                    let missing_cycles = (1_000_000_000 - i) % repeat_period;
+                    limit = i + missing_cycles - 1;
+                    debug!(target: "day14::cycles", "Continuing only until cycle {limit}, which will match the end state");
+                    continue;
+                } else {
+                    cache.insert(platform.tiles.clone(), i);
+                }
+            }
+            i += 1;
+        }
+        Ok(platform.calculate_north_load().to_string())
+    }
+
+}
+
+impl Platform {
+
+    fn tilt_north_and_calculate_load(&self) -> u32 {
+        let mut stops = vec![0; self.tiles.num_cols()];
+        let mut load = 0;
+        let max_load = self.tiles.num_rows() as u32;
+
+        for r in 0 .. self.tiles.num_rows() {
+            let row = &self.tiles[r];
+            for c in 0 .. row.len() {
+                if row[c] == Tile::CubeRock {
+                    stops[c] = r + 1;
+                } else if row[c] == Tile::RoundRock {
+                    load += max_load - (stops[c] as u32);
+                    stops[c] += 1;
+                }
+            }
+        }
+
+        load
+    }
+
+    fn tilt_north(&mut self) {
+        let mut stops = vec![0; self.tiles.num_cols()];
+
+        for r in 0 .. self.tiles.num_rows() {
+            for (c, stop) in stops.iter_mut().enumerate() {
+                if self.tiles[r][c] == Tile::CubeRock {
+                    *stop = r + 1;
+                } else if self.tiles[r][c] == Tile::RoundRock {
+                    self.tiles[r][c] = Tile::Empty;
+                    self.tiles[*stop][c] = Tile::RoundRock;
+                    *stop += 1;
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for r in 0 .. self.tiles.num_rows() {
+            let row = &mut self.tiles[r];
+            let mut stop = 0;
+            for c in 0 .. row.len() {
+                if row[c] == Tile::CubeRock {
+                    stop = c + 1;
+                } else if row[c] == Tile::RoundRock {
+                    row[c] = Tile::Empty;
+                    row[stop] = Tile::RoundRock;
+                    stop += 1;
+                }
+            }
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        let mut stops = vec![self.tiles.num_rows() - 1; self.tiles.num_cols()];
+        
+        for r in (0 .. self.tiles.num_rows()).rev() {
+            for (c, stop) in stops.iter_mut().enumerate() {
+                if self.tiles[r][c] == Tile::CubeRock && r > 0 {
+                    *stop = r - 1;
+                } else if self.tiles[r][c] == Tile::RoundRock {
+                    self.tiles[r][c] = Tile::Empty;
+                    self.tiles[*stop][c] = Tile::RoundRock;
+
+                    if *stop > 0 {
+                        *stop -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for r in 0 .. self.tiles.num_rows() {
+            let row = &mut self.tiles[r];
+            let mut stop = row.len() - 1;
+            for c in (0 .. row.len()).rev() {
+                if row[c] == Tile::CubeRock && c > 0 {
+                    stop = c - 1;
+                } else if row[c] == Tile::RoundRock {
+                    row[c] = Tile::Empty;
+                    row[stop] = Tile::RoundRock;
+
+                    stop = stop.saturating_sub(1);
+                }
+            }
+        }
+    }
+
+    fn calculate_north_load(&self) -> usize {
+        let max_load = self.tiles.num_rows();
+        let mut load = 0;
+
+        for r in 0 .. self.tiles.num_rows() {
+            let total_round_rocks = self.tiles[r].iter().filter(|&&t| t == Tile::RoundRock).count();
+            load += (max_load - r) * total_round_rocks;
+        }
+
+        load
+    }
+
+}
+
+impl core::fmt::Debug for Tile {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+
+}
+
+impl core::fmt::Debug for Platform {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.tiles)
+    }
+
+}
@@ -0,0 +1,112 @@
+use crate::{Error, Result, Solution};
+use crate::str::StringOps;
+
+enum Operation {
+    Remove,
+    Insert(u32)
+}
+
+struct Step {
+    // As given, which is what part 1 hashes
+    text: String,
+    label: String,
+    operation: Operation
+}
+
+pub struct InitSequence {
+    steps: Vec<Step>
+}
+
+impl Solution for InitSequence {
+
+    fn parse(content: &str) -> Result<InitSequence> {
+        let steps = content
+            .split(',')
+            .map(|str| Step::parse(str.trim()))
+            .collect::<Result<_>>()?;
+
+        Ok(InitSequence { steps })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.steps
+            .iter()
+            .map(|s| hash(&s.text))
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        const DEFAULT_BOX: Vec<(String, u32)> = vec![];
+        let mut boxes = [DEFAULT_BOX; 256];
+        
+        'steps: for Step { label, operation, .. } in &self.steps {
+            let b = hash(label) as usize;
+            match *operation {
+                Operation::Remove => {
+                    if let Some(i) = boxes[b].iter().position(|(l, _)| l == label) {
+                        boxes[b].remove(i);
+                    }
+                },
+                Operation::Insert(focal_len) => {
+                    for (l, fl) in boxes[b].iter_mut() {
+                        if l == label {
+                            *fl = focal_len;
+                            continue 'steps;
+                        }
+                    }
+
+                    boxes[b].push((label.to_string(), focal_len));
+                }
+            }
+        }
+        
+        Ok(boxes
+            .iter()
+            .enumerate()
+            .map(|(i, lenses)| lenses
+                .iter()
+                .enumerate()
+                .map(|(j, (_, f))| (i as u32 + 1) * (j as u32 + 1) * f)
+                .sum::<u32>()
+            )
+            .sum::<u32>()
+            .to_string())
+    }
+
+}
+
+impl Step {
+
+    fn parse(text: &str) -> Result<Step> {
+        let (label, operation) = if let Some(label) = text.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else {
+            let (label, focal_len_str) = text
+                .split_once('=')
+                .ok_or_else(|| Error::parse(text, "expected step like rn=1 or cm-"))?;
+            let focal_len = focal_len_str.try_parse()?;
+            if !(1 ..= 9).contains(&focal_len) {
+                return Err(Error::parse(focal_len_str, "expected focal length from 1 to 9"));
+            }
+            (label, Operation::Insert(focal_len))
+        };
+
+        if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(Error::parse(text, "expected label of lowercase letters"));
+        }
+
+        Ok(Step { text: text.to_string(), label: label.to_string(), operation })
+    }
+
+}
+
+fn hash(s: &str) -> u32 {
+    let mut hash = 0;
+    for c in s.chars() {
+        hash += c as u32;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
\ No newline at end of file
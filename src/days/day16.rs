@@ -1,18 +1,16 @@
-use std::mem::transmute;
-use std::ops::BitOrAssign;
-use std::time::Instant;
-use std::fs;
-use adventofcode2023::{Result, Part};
-
-#[allow(dead_code)]
-#[derive(PartialEq, Clone, Copy)]
-#[repr(u8)]
-enum Tile {
-    Empty = b'.',
-    MirrorLeftRaising = b'/',
-    MirrorLeftLowering = b'\\',
-    HorizontalSplitter = b'-',
-    VerticalSplitter = b'|',
+use log::trace;
+use crate::{Result, Solution, char_enum};
+use crate::grid::Grid;
+
+char_enum! {
+    #[derive(PartialEq, Clone, Copy)]
+    enum Tile {
+        Empty = b'.',
+        MirrorLeftRaising = b'/',
+        MirrorLeftLowering = b'\\',
+        HorizontalSplitter = b'-',
+        VerticalSplitter = b'|',
+    }
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -27,96 +25,83 @@ enum Energized {
     FromVertical = (Energized::FromUp as u8) | (Energized::FromDown as u8),
 }
 
-struct Contraption {
-    layout: Vec<Vec<Tile>>
+pub struct Contraption {
+    layout: Grid<Tile>
 }
 
-fn main() {
-    println!("example (part1): {:?}", solve("inputs/day16_example", Part::One));
-    let time_part1 = Instant::now();
-    println!("input (part1): {:?} ({} µs)", solve("inputs/day16", Part::One), time_part1.elapsed().as_micros());
+impl Solution for Contraption {
 
-    let time_part2_ex = Instant::now();
-    println!("example (part2): {:?} ({} µs)", solve("inputs/day16_example", Part::Two), time_part2_ex.elapsed().as_micros());
-    let time_part2 = Instant::now();
-    println!("input (part2): {:?} ({} µs)", solve("inputs/day16", Part::Two), time_part2.elapsed().as_micros());
-}
+    fn parse(content: &str) -> Result<Contraption> {
+        let layout = Grid::parse(content, Tile::try_from)?;
 
-fn solve(path: &str, part: Part) -> Result<u32> {
-    let content = fs::read_to_string(path)?;
-    let contraption = Contraption::parse(&content);
+        Ok(Contraption { layout })
+    }
 
-    Ok(match part {
-        Part::One => contraption.count_energized(0, 0, Energized::FromLeft),
-        
-        Part::Two => {
-            let max_r = contraption.layout.len() - 1;
-            let max_c = contraption.layout[0].len() - 1;
-
-            let mut max_energized = 0;
-            for r in 0 ..= max_r {
-                let e = contraption.count_energized(r, 0, Energized::FromLeft);
-                if e > max_energized {
-                    max_energized = e;
-                }
+    fn part1(&self) -> Result<String> {
+        Ok(self.count_energized(0, 0, Energized::FromLeft).to_string())
+    }
 
-                let e = contraption.count_energized(r, max_c, Energized::FromRight);
-                if e > max_energized {
-                    max_energized = e;
-                }
+    fn part2(&self) -> Result<String> {
+        let max_r = self.layout.num_rows() - 1;
+        let max_c = self.layout.num_cols() - 1;
+
+        let mut max_energized = 0;
+        for r in 0 ..= max_r {
+            let e = self.count_energized(r, 0, Energized::FromLeft);
+            if e > max_energized {
+                max_energized = e;
             }
 
-            for c in 0 ..= max_c {
-                let e = contraption.count_energized(0, c, Energized::FromUp);
-                if e > max_energized {
-                    max_energized = e;
-                }
+            let e = self.count_energized(r, max_c, Energized::FromRight);
+            if e > max_energized {
+                max_energized = e;
+            }
+        }
 
-                let e = contraption.count_energized(max_r, c, Energized::FromDown);
-                if e > max_energized {
-                    max_energized = e;
-                }
+        for c in 0 ..= max_c {
+            let e = self.count_energized(0, c, Energized::FromUp);
+            if e > max_energized {
+                max_energized = e;
             }
 
-            max_energized
+            let e = self.count_energized(max_r, c, Energized::FromDown);
+            if e > max_energized {
+                max_energized = e;
+            }
         }
-    })
-}
 
-impl Contraption {
+        Ok(max_energized.to_string())
+    }
 
-    fn parse(content: &str) -> Contraption {
-        let layout = content
-            .lines()
-            .map(|l| l.chars().map(|c| Tile::parse(c)).collect())
-            .collect();
+}
 
-        Contraption { layout }
-    }
+impl Contraption {
 
     fn count_energized(&self, start_r: usize, start_c: usize, start_dir: Energized) -> u32 {
-        let mut energized_cells = vec![vec![Energized::No; self.layout[0].len()]; self.layout.len()];
+        // Each cell holds the union of the directions it was energized from
+        let mut energized_cells = Grid::new(self.layout.num_rows(), self.layout.num_cols(), Energized::No as u8);
         self.simulate_beam(start_r, start_c, start_dir, &mut energized_cells);
         energized_cells
             .iter()
-            .map(|e_row| e_row.iter().filter(|&&e| e != Energized::No).count() as u32)
-            .sum()
+            .filter(|&&e| e != Energized::No as u8)
+            .count() as u32
     }
 
-    fn simulate_beam(&self, start_r: usize, start_c: usize, start_dir: Energized, energized_cells: &mut Vec<Vec<Energized>>) {
-        let max_r = self.layout.len() - 1;
-        let max_c = self.layout[0].len() - 1;
+    fn simulate_beam(&self, start_r: usize, start_c: usize, start_dir: Energized, energized_cells: &mut Grid<u8>) {
+        let max_r = self.layout.num_rows() - 1;
+        let max_c = self.layout.num_cols() - 1;
         
         let mut r = start_r;
         let mut c = start_c;
         let mut dir = start_dir;
 
         loop {
-            if energized_cells[r][c].includes(dir) {
+            if energized_cells[r][c] & dir as u8 == dir as u8 {
+                trace!(target: "day16::beams", "Stopping at r={r} c={c} with {dir:?}, as the cell already is {:#06b}", energized_cells[r][c]);
                 break;
             }
-            energized_cells[r][c] |= dir;
-            // println!("Processing r={r} c={c} with {dir:?}");
+            energized_cells[r][c] |= dir as u8;
+            trace!(target: "day16::beams", "Processing r={r} c={c} with {dir:?}");
 
             match self.layout[r][c] {
                 t if t == Tile::Empty 
@@ -181,15 +166,6 @@ impl Contraption {
             }
             
         }
-        //println!("Stopping simulation at r={r}, c={c} with {dir:?}, as cell already is {:?}", energized_cells[r][c]);
-    }
-
-}
-
-impl Tile {
-
-    fn parse(c: char) -> Tile {
-        unsafe { transmute(c as u8) }
     }
 
 }
@@ -201,11 +177,3 @@ impl Energized {
     }
 
 }
-
-impl BitOrAssign for Energized {
-    
-    fn bitor_assign(&mut self, rhs: Self) {
-        *self = unsafe { transmute((*self as u8) | (rhs as u8)) }
-    }
-
-}
\ No newline at end of file
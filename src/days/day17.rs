@@ -1,12 +1,16 @@
 use std::collections::HashMap;
 use std::cmp::Reverse;
+use log::{Level, log_enabled, debug, trace};
 use priority_queue::PriorityQueue;
-use adventofcode2023::{Result, Part, run};
+use crate::{Result, Solution};
+use crate::grid::Grid;
 
-struct HeatMap {
-    map: Vec<Vec<u8>>
+pub struct HeatMap {
+    map: Grid<u8>
 }
 
+type MinHeats = Grid<HashMap<(isize, isize, u8), u32>>;
+
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 struct CrucibleCell {
     r: usize,
@@ -16,57 +20,45 @@ struct CrucibleCell {
     same_dir_budget: u8
 }
 
-fn main() {
-    run("inputs/day17_example", Part::One, solve);
-    run("inputs/day17", Part::One, solve);
+impl Solution for HeatMap {
 
-    run("inputs/day17_example", Part::Two, solve);
-    run("inputs/day17_example2", Part::Two, solve);
-    run("inputs/day17", Part::Two, solve);
-}
+    fn parse(content: &str) -> Result<HeatMap> {
+        let map = Grid::parse(content, |c| c
+            .to_digit(10)
+            .map(|d| d as u8)
+            .ok_or_else(|| "expected a heat loss digit".into())
+        )?;
 
-fn solve(content: &str, part: Part) -> Result<u32> {
-    let heat_map = HeatMap::parse(&content);
+        Ok(HeatMap { map })
+    }
 
-    let start_r = 0;
-    let start_c = 0;
-    let target_r = heat_map.num_rows() - 1;
-    let target_c = heat_map.num_cols() - 1;
+    fn part1(&self) -> Result<String> {
+        Ok(self.min_heat_to_bottom_right(0, 3).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(self.min_heat_to_bottom_right(4, 10).to_string())
+    }
 
-    Ok(match part {
-        Part::One => heat_map.min_heat(start_r, start_c, target_r, target_c, 0, 3),
-        
-        Part::Two => heat_map.min_heat(start_r, start_c, target_r, target_c, 4, 10)
-    })
 }
 
 impl HeatMap {
 
-    fn parse(content: &str) -> HeatMap {
-        let map = content
-            .lines()
-            .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
-            .collect();
+    fn min_heat_to_bottom_right(&self, min_same_dir: u8, max_same_dir: u8) -> u32 {
+        let start_r = 0;
+        let start_c = 0;
+        let target_r = self.map.num_rows() - 1;
+        let target_c = self.map.num_cols() - 1;
 
-        HeatMap { map }
-    }
-
-    fn num_rows(&self) -> usize {
-        self.map.len()
-    }
-
-    fn num_cols(&self) -> usize {
-        self.map[0].len()
+        self.min_heat(start_r, start_c, target_r, target_c, min_same_dir, max_same_dir)
     }
     
     fn min_heat(&self, sr: usize, sc: usize, tr: usize, tc: usize, min_same_dir: u8, max_same_dir: u8) -> u32 {
-        let num_rows = self.num_rows();
-        let num_cols = self.num_cols();
         let min_required_budget = max_same_dir - min_same_dir;
 
         // To ensure we explore the variations coming from all 4 directions + budget
         // store the min heats for each and evaluate at the end
-        let mut min_heats = vec![vec![HashMap::new(); num_cols]; num_rows];
+        let mut min_heats: MinHeats = Grid::new(self.map.num_rows(), self.map.num_cols(), HashMap::new());
         let mut to_visit = PriorityQueue::<CrucibleCell, Reverse<u32>>::new();
 
         min_heats[sr][sc].insert((0, 0, max_same_dir), 0);
@@ -75,11 +67,9 @@ impl HeatMap {
         while let Some((cell, Reverse(min_heat))) = to_visit.pop() {
             for (dr, dc) in [(0, 1), (1, 0), (-1, 0), (0, -1)] {
                 // If invalid indexes, ignore
-                let nr = cell.r as isize + dr;
-                let nc = cell.c as isize + dc;
-                if nr < 0 || (nr as usize) >= num_rows || nc < 0 || (nc as usize) >= num_cols {
+                let Some((nr, nc)) = self.map.offset(cell.r, cell.c, dr, dc) else {
                     continue;
-                }
+                };
 
                 // If turning back, ignore
                 if (dr == 0 && dc == -cell.dc) || (dc == 0 && dr == -cell.dr) {
@@ -100,8 +90,6 @@ impl HeatMap {
                     same_dir_budget = max_same_dir - 1;
                 }
 
-                let nr = nr as usize;
-                let nc = nc as usize;
                 let n_min_heat = min_heat + self.map[nr][nc] as u32;
                 if let Some(old_n_min_heat) = min_heats[nr][nc].get_mut(&(dr, dc, same_dir_budget)) {
                     if *old_n_min_heat > n_min_heat {
@@ -113,11 +101,12 @@ impl HeatMap {
                     to_visit.push(CrucibleCell { r: nr, c: nc, dr, dc, same_dir_budget }, Reverse(n_min_heat));
                 }
             }
-            // println!("{:?} with heat {}", cell, min_heat);
-            // println!("{:?}", to_visit.clone().into_sorted_vec());
+            trace!(target: "day17::search", "{cell:?} with heat {min_heat}");
         }
 
-        // print_min_heats(&min_heats);
+        if log_enabled!(target: "day17::heats", Level::Debug) {
+            debug!(target: "day17::heats", "Min heats:\n{}", format_min_heats(&min_heats));
+        }
         *min_heats[tr][tc]
             .iter()
             .filter(|((_, _, budget), _)| *budget <= min_required_budget)
@@ -128,19 +117,19 @@ impl HeatMap {
 
 }
 
-#[allow(dead_code)]
-fn print_min_heats(matrix: &Vec<Vec<HashMap<(isize, isize, u8), u32>>>) {
-    for row in matrix {
-        println!("{}", row
+fn format_min_heats(matrix: &MinHeats) -> String {
+    matrix
+        .rows()
+        .map(|row| row
             .iter()
             .map(|ns| ns
-                .iter()
-                .map(|(_, h)| h)
+                .values()
                 .min()
                 .map(|n| n.to_string())
                 .unwrap_or("?".to_string())
             )
             .collect::<Vec<String>>()
-            .join("\t"));
-    }
+            .join("\t"))
+        .collect::<Vec<String>>()
+        .join("\n")
 }
\ No newline at end of file
@@ -1,22 +1,27 @@
-use adventofcode2023::{Result, Part, run};
-
-#[derive(PartialEq, Clone, Copy, Debug)]
-#[repr(u8)]
-enum Direction {
-    Up = b'U',
-    Down = b'D',
-    Left = b'L',
-    Right = b'R'
+use log::{debug, trace};
+use crate::{Error, Result, Solution, char_enum};
+use crate::str::StringOps;
+use crate::grid::Grid;
+
+char_enum! {
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    enum Direction {
+        Up = b'U',
+        Down = b'D',
+        Left = b'L',
+        Right = b'R'
+    }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct DigInstruction {
     direction: Direction,
     distance: u32,
     color: String
 }
 
-struct DigPlan {
+#[derive(Clone)]
+pub struct DigPlan {
     instructions: Vec<DigInstruction>,
 }
 
@@ -27,63 +32,58 @@ struct LagoonSpan {
     start_c: usize
 }
 
-#[derive(Copy, Clone, PartialEq)]
-#[repr(u8)]
-enum DigSite {
-    NotDug = b'.',
-    Dug = b'#',
-    NorthAndSouthBorder = b'|',
-    NorthAndEastBorder = b'L',
-    NorthAndWestBorder = b'J',
-    WestAndEastBorder = b'-',
-    SouthAndWestBorder = b'7',
-    SouthAndEastBorder = b'F',
+char_enum! {
+    #[derive(Copy, Clone, PartialEq)]
+    enum DigSite {
+        NotDug = b'.',
+        Dug = b'#',
+        NorthAndSouthBorder = b'|',
+        NorthAndEastBorder = b'L',
+        NorthAndWestBorder = b'J',
+        WestAndEastBorder = b'-',
+        SouthAndWestBorder = b'7',
+        SouthAndEastBorder = b'F',
+    }
 }
 
-fn main() {
-    run("inputs/day18_example", Part::One, solve);
-    run("inputs/day18", Part::One, solve);
+impl Solution for DigPlan {
 
-    run("inputs/day18_example", Part::Two, solve);
-    run("inputs/day18", Part::Two, solve);
-}
+    fn parse(content: &str) -> Result<DigPlan> {
+        let instructions = content
+            .lines()
+            .map(DigInstruction::parse)
+            .collect::<Result<_>>()?;
 
-fn solve(content: &str, part: Part) -> Result<u64> {
-    let mut dig_plan = DigPlan::parse(&content);
-
-    Ok(match part {
-        Part::One => dig_plan.get_lagoon_capacity() as u64,
-        
-        Part::Two => {
-            for instr in dig_plan.instructions.as_mut_slice() {
-                let color_len = instr.color.len();
-                
-                instr.distance = u32::from_str_radix(&instr.color[1 .. (color_len - 1)], 16)?;
-                instr.direction = match &instr.color[color_len - 1 ..] {
-                    "0" => Direction::Right,
-                    "1" => Direction::Down,
-                    "2" => Direction::Left,
-                    "3" => Direction::Up,
-                    _ => panic!()
-                }
-            }
+        Ok(DigPlan { instructions })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.get_lagoon_capacity().to_string())
+    }
 
-            // Original solution for part 1 gave an OOM straightaway
-            dig_plan.get_lagoon_capacity_by_maths()
+    fn part2(&self) -> Result<String> {
+        let mut dig_plan = self.clone();
+        for instr in dig_plan.instructions.as_mut_slice() {
+            let color_len = instr.color.len();
+            
+            instr.distance = u32::from_str_radix(&instr.color[1 .. (color_len - 1)], 16)
+                .map_err(|err| Error::parse(&instr.color, format!("{err}")))?;
+            instr.direction = match &instr.color[color_len - 1 ..] {
+                "0" => Direction::Right,
+                "1" => Direction::Down,
+                "2" => Direction::Left,
+                "3" => Direction::Up,
+                _ => return Err(format!("invalid direction in color {}", instr.color).into())
+            }
         }
-    })
-}
 
-impl DigPlan {
+        // Original solution for part 1 gave an OOM straightaway
+        Ok(dig_plan.get_lagoon_capacity_by_maths().to_string())
+    }
 
-    fn parse(content: &str) -> DigPlan {
-        let instructions = content
-            .lines()
-            .map(|l| DigInstruction::parse(&l).unwrap())
-            .collect();
+}
 
-        DigPlan { instructions }
-    }
+impl DigPlan {
 
     fn get_lagoon_span(&self) -> LagoonSpan {
         let mut max_width = 1;
@@ -117,8 +117,8 @@ impl DigPlan {
         LagoonSpan {
             num_rows: (max_height - min_height + 1) as usize,
             num_cols: (max_width - min_width + 1) as usize,
-            start_r: (min_height - 1).abs() as usize,
-            start_c: (min_width - 1).abs() as usize
+            start_r: (min_height - 1).unsigned_abs() as usize,
+            start_c: (min_width - 1).unsigned_abs() as usize
         }
     }
 
@@ -128,8 +128,8 @@ impl DigPlan {
         let mut r = start_r;
         let mut c = start_c;
         let mut prev_dir = self.instructions.last().unwrap().direction;
-        let mut dig_sites = vec![vec![DigSite::NotDug; num_cols]; num_rows];
-        println!("Start {start_r} and {start_c} and {num_rows} and {num_cols}");
+        let mut dig_sites = Grid::new(num_rows, num_cols, DigSite::NotDug);
+        debug!(target: "day18", "Starting at ({start_r}, {start_c}) of a {num_rows}x{num_cols} lagoon");
 
         for instr in &self.instructions {
             dig_sites[r][c] = DigSite::from_directions(prev_dir, instr.direction);
@@ -165,30 +165,26 @@ impl DigPlan {
                 }
             }
             prev_dir = instr.direction;
-            // println!("Now at {r} and {c} after executing {:?}", instr)
+            trace!(target: "day18::dig", "Now at ({r}, {c}) after executing {instr:?}");
         }
 
         // Fix start
         dig_sites[start_r][start_c] = DigSite::from_directions(prev_dir, self.instructions[0].direction);
 
-        // for row in &dig_sites {
-        //     println!("{}", row.iter().map(|&ds| ds.to_char().to_string()).collect::<Vec<_>>().join(""));
-        // }
-        // println!();
+        debug!(target: "day18::dig", "Border:\n{dig_sites}");
 
         // Dig inside
         // Initialize a slightly bigger matrix to store if the corners are outside or inside.
         // We know the outer corners all around are necessarily outside, so those get marked right away.
-        let outside_len = num_rows + 1;
-        let outside_row_len = num_cols + 1;
-
-        let mut sample_outside = vec![None; outside_row_len];
-        sample_outside[0] = Some(true);
-        sample_outside[outside_row_len - 1] = Some(true);
-        
-        let mut outside = vec![sample_outside; outside_len];
-        outside[0] = vec![Some(true); outside_row_len];
-        outside[outside_len - 1] = vec![Some(true); outside_row_len];
+        let mut outside = Grid::new(num_rows + 1, num_cols + 1, None);
+        for r in 0 ..= num_rows {
+            outside[r][0] = Some(true);
+            outside[r][num_cols] = Some(true);
+        }
+        for c in 0 ..= num_cols {
+            outside[0][c] = Some(true);
+            outside[num_rows][c] = Some(true);
+        }
 
         // For each map position, keep updating the southwest and southeast corners depending on the shape
         // of the boundary. At the same time, use the northeast corner info to understand if undug positions
@@ -199,7 +195,7 @@ impl DigPlan {
                 outside[r + 1][1] = Some(true);
             }
 
-            for c in 0 .. dig_sites[r].len() {
+            for c in 0 .. num_cols {
                 let is_northwest_outside = outside[r][c];
 
                 match dig_sites[r][c] {
@@ -240,14 +236,12 @@ impl DigPlan {
             }
         }
 
-        // for row in &dig_sites {
-        //     println!("{}", row.iter().map(|&ds| ds.to_char().to_string()).collect::<Vec<_>>().join(""));
-        // }
+        debug!(target: "day18::dig", "Dug:\n{dig_sites}");
 
         dig_sites
             .iter()
-            .map(|row| row.iter().filter(|&&ds| ds != DigSite::NotDug).count() as u32)
-            .sum()
+            .filter(|&&ds| ds != DigSite::NotDug)
+            .count() as u32
     }
 
     fn get_lagoon_capacity_by_maths(&self) -> u64 {
@@ -272,31 +266,46 @@ impl DigPlan {
             area += points[i-1].0 * points[i].1 - points[i].0 * points[i-1].1;
         }
 
-        return 1 + (total_dist / 2 + area.abs() / 2) as u64;
+        1 + (total_dist / 2 + area.abs() / 2) as u64
     }
 
 }
 
 impl DigInstruction {
 
-    fn parse(line: &str) -> Option<DigInstruction> {
+    fn parse(line: &str) -> Result<DigInstruction> {
         let mut words_it = line.split_whitespace();
-        let direction = Direction::parse(&words_it.next()?);
-        let distance = words_it.next()?.parse().ok()?;
-        let color = words_it.next()?
+        let mut next_word = || words_it
+            .next()
+            .ok_or_else(|| Error::parse(line, "expected direction, distance and color"));
+
+        let direction = Direction::parse(next_word()?)?;
+        let distance = next_word()?.try_parse()?;
+        let color_str = next_word()?;
+        let color = color_str
             .trim_start_matches('(')
-            .trim_end_matches(')')
-            .to_string();
-        Some(DigInstruction { direction, distance, color })
+            .trim_end_matches(')');
+
+        // Part 2 reads the real instruction from the color, so it must be well-formed
+        let is_hex_color = color.len() == 7
+            && color.starts_with('#')
+            && color[1 ..].chars().all(|c| c.is_ascii_hexdigit());
+        if !is_hex_color {
+            return Err(Error::parse(color_str, "expected color like (#70c710)"));
+        }
+
+        Ok(DigInstruction { direction, distance, color: color.to_string() })
     }
 
 }
 
 impl Direction {
 
-    fn parse(s: &str) -> Direction {
-        let first_char = s.chars().take(1).collect::<Vec<_>>()[0];
-        unsafe { std::mem::transmute(first_char as u8) }
+    fn parse(s: &str) -> Result<Direction> {
+        match s.try_parse_chars()?[..] {
+            [direction] => Ok(direction),
+            _ => Err(Error::parse(s, "expected a single direction"))
+        }
     }
 
 }
@@ -321,9 +330,4 @@ impl DigSite {
         }
     }
 
-    #[allow(dead_code)]
-    fn to_char(&self) -> char {
-        char::from(*self as u8)
-    }
-
 }
\ No newline at end of file
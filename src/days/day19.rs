@@ -0,0 +1,426 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use regex::Regex;
+use lazy_static::lazy_static;
+use log::{trace, warn};
+use crate::{Error, Result, Solution, str::StringOps};
+use crate::dot::{Digraph, ToDot};
+
+lazy_static! {
+    static ref COND_REGEX: Regex = Regex::new(r"(?P<field>[xmas])(?P<op><|>)(?P<value>\d+)").unwrap();
+    static ref PART_REGEX: Regex = Regex::new(r"\{x=(?P<x>\d+),m=(?P<m>\d+),a=(?P<a>\d+),s=(?P<s>\d+)\}").unwrap();
+}
+
+type WorkflowName = String;
+
+#[derive(Clone, Copy)]
+enum Op {
+    LessThan,
+    GreaterThan
+}
+
+struct Rule {
+    field: char,
+    op: Op,
+    value: u32,
+    target: WorkflowName
+}
+
+struct Workflow {
+    rules: Vec<Rule>,
+    // Where parts go when none of the rules match
+    default: WorkflowName
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MachinePart {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32
+}
+
+// All the parts whose ratings fall within these ranges
+#[derive(Clone, Debug)]
+struct MachinePartRanges {
+    x: RangeInclusive<u32>,
+    m: RangeInclusive<u32>,
+    a: RangeInclusive<u32>,
+    s: RangeInclusive<u32>
+}
+
+/// The workflows the parts are sorted through, starting at `in` until reaching either `A`ccepted or `R`ejected.
+/// Prints back in the input syntax.
+pub struct Workflows {
+    nodes: HashMap<WorkflowName, Workflow>,
+    // To print them in the order they were given
+    names: Vec<WorkflowName>
+}
+
+pub struct PartSorter {
+    workflows: Workflows,
+    machine_parts: Vec<MachinePart>
+}
+
+impl Solution for PartSorter {
+
+    fn parse(content: &str) -> Result<PartSorter> {
+        let [workflows_block, parts_block] = content.split_blocks()[..] else {
+            return Err("Expected a block of workflows followed by a block of parts".into());
+        };
+
+        let workflows = Workflows::parse(workflows_block)?;
+        let machine_parts = parts_block
+            .lines()
+            .map(MachinePart::parse)
+            .collect::<Result<_>>()?;
+
+        Ok(PartSorter { workflows, machine_parts })
+    }
+
+    fn part1(&self) -> Result<String> {
+        Ok(self.machine_parts
+            .iter()
+            .filter(|mp| self.workflows.is_accepted(mp))
+            .map(|mp| mp.value())
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let all_parts = MachinePartRanges { x: 1 ..= 4000, m: 1 ..= 4000, a: 1 ..= 4000, s: 1 ..= 4000 };
+        Ok(self.workflows.count_accepted(all_parts).to_string())
+    }
+
+}
+
+impl Workflows {
+
+    pub fn parse(block: &str) -> Result<Workflows> {
+        let mut nodes = HashMap::new();
+        let mut names = vec![];
+        // The same workflows as slices of the block, so that validation errors can point into it
+        let mut graph = vec![];
+        for line in block.lines() {
+            let (name, flow_str) = line.try_split_once("{")?;
+            let rules_str = flow_str
+                .strip_suffix('}')
+                .ok_or_else(|| Error::parse(line, "expected workflow like px{a<2006:qkq,m>2090:A,rfg}"))?;
+            let (rules_str, default) = rules_str.rsplit_once(',').unwrap_or(("", rules_str));
+
+            let rules_strs: Vec<_> = rules_str
+                .split(',')
+                .filter(|rule_str| !rule_str.is_empty())
+                .collect();
+            let rules = rules_strs
+                .iter()
+                .map(|rule_str| Rule::parse(rule_str))
+                .collect::<Result<_>>()?;
+            let targets: Vec<_> = rules_strs
+                .iter()
+                .filter_map(|rule_str| rule_str.split_once(':'))
+                .map(|(_, target)| target)
+                .chain(std::iter::once(default))
+                .collect();
+            graph.push((name, targets));
+
+            if nodes.insert(name.to_string(), Workflow { rules, default: default.to_string() }).is_some() {
+                return Err(Error::parse(name, "duplicate workflow"));
+            }
+            names.push(name.to_string());
+        }
+
+        Workflows::validate(&graph)?;
+        Ok(Workflows { nodes, names })
+    }
+
+    // Checks that every target is a known workflow and that no part can go around in circles.
+    // Workflows that can't be reached from `in` are harmless, so those only get a warning.
+    fn validate(graph: &[(&str, Vec<&str>)]) -> Result<()> {
+        let edges: HashMap<_, _> = graph
+            .iter()
+            .map(|(name, targets)| (*name, targets))
+            .collect();
+
+        let known_targets = |name: &str| name == "A" || name == "R" || edges.contains_key(name);
+        for (name, targets) in graph {
+            if let Some(target) = targets.iter().find(|t| !known_targets(t)) {
+                return Err(Error::parse(target, format!("workflow {name} sends parts to an unknown workflow")));
+            }
+        }
+
+        if !edges.contains_key("in") {
+            return Err("Missing the in workflow".into());
+        }
+
+        // Depth-first from `in`, where reaching a workflow still on the path means there's a cycle
+        let mut visited = HashSet::new();
+        let mut on_path = vec![];
+        let mut to_visit = vec![(false, "in")];
+        while let Some((is_leaving, name)) = to_visit.pop() {
+            if is_leaving {
+                on_path.pop();
+                continue;
+            }
+            if on_path.contains(&name) {
+                on_path.push(name);
+                return Err(Error::parse(name, format!("workflows cycle through {}", on_path.join(" -> "))));
+            }
+            if name == "A" || name == "R" || !visited.insert(name) {
+                continue;
+            }
+
+            on_path.push(name);
+            to_visit.push((true, name));
+            for target in edges[name] {
+                to_visit.push((false, target));
+            }
+        }
+
+        for (name, _) in graph.iter().filter(|(name, _)| !visited.contains(name)) {
+            warn!(target: "day19", "Workflow {name} can't be reached from in");
+        }
+
+        Ok(())
+    }
+
+    fn is_accepted(&self, mp: &MachinePart) -> bool {
+        let mut curr_state = String::from("in");
+        while curr_state != "A" && curr_state != "R" {
+            curr_state = self.nodes[&curr_state].next(mp).clone();
+        }
+        trace!(target: "day19::workflows", "{mp:?} ended in {curr_state}");
+        curr_state == "A"
+    }
+
+    // Follows the ranges through the workflows, splitting them whenever a rule only matches some of the parts
+    fn count_accepted(&self, all_parts: MachinePartRanges) -> u64 {
+        let mut total = 0;
+        let mut to_visit = vec![(all_parts, "in")];
+        while let Some((mut parts, curr_state)) = to_visit.pop() {
+            match curr_state {
+                "A" => {
+                    total += parts.count();
+                    continue;
+                },
+                "R" => continue,
+                _ => ()
+            }
+
+            let workflow = &self.nodes[curr_state];
+
+            let mut has_remaining_parts = true;
+            for rule in &workflow.rules {
+                let (matching, remaining) = parts.split(rule);
+                if let Some(matching) = matching {
+                    to_visit.push((matching, &rule.target));
+                }
+                match remaining {
+                    Some(remaining) => parts = remaining,
+                    None => {
+                        has_remaining_parts = false;
+                        break;
+                    }
+                }
+            }
+            if has_remaining_parts {
+                to_visit.push((parts, &workflow.default));
+            }
+        }
+
+        total
+    }
+
+}
+
+impl Workflow {
+
+    fn next(&self, mp: &MachinePart) -> &WorkflowName {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(mp))
+            .map(|rule| &rule.target)
+            .unwrap_or(&self.default)
+    }
+
+}
+
+impl Rule {
+
+    fn parse(rule_str: &str) -> Result<Rule> {
+        let (cond_str, target) = rule_str.try_split_once(":")?;
+        let cond_captures = COND_REGEX
+            .captures(cond_str)
+            .filter(|c| c[0].len() == cond_str.len())
+            .ok_or_else(|| Error::parse(cond_str, "expected condition like a<2006"))?;
+
+        let field = cond_captures["field"].chars().next().unwrap();
+        let op = if &cond_captures["op"] == ">" {
+            Op::GreaterThan
+        } else {
+            Op::LessThan
+        };
+        let value = cond_captures["value"].try_parse()?;
+
+        Ok(Rule { field, op, value, target: target.to_string() })
+    }
+
+    fn matches(&self, mp: &MachinePart) -> bool {
+        let rating = mp.get(self.field);
+        match self.op {
+            Op::LessThan => rating < self.value,
+            Op::GreaterThan => rating > self.value
+        }
+    }
+
+}
+
+impl ToDot for PartSorter {
+
+    fn to_dot(&self) -> Digraph {
+        self.workflows.to_dot()
+    }
+
+}
+
+// Each rule is an edge labelled with its condition, while the default one is labelled `else`
+impl ToDot for Workflows {
+
+    fn to_dot(&self) -> Digraph {
+        let mut graph = Digraph::new("workflows");
+        graph.node("A", "A", "doublecircle");
+        graph.node("R", "R", "octagon");
+        for name in &self.names {
+            let workflow = &self.nodes[name];
+            graph.node(name, name, if name == "in" { "house" } else { "box" });
+            for rule in &workflow.rules {
+                graph.edge(name, &rule.target, Some(&format!("{}{}{}", rule.field, rule.op, rule.value)));
+            }
+            graph.edge(name, &workflow.default, Some("else"));
+        }
+        graph
+    }
+
+}
+
+impl Display for Workflows {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for name in &self.names {
+            writeln!(f, "{name}{}", self.nodes[name])?;
+        }
+        Ok(())
+    }
+
+}
+
+impl Display for Workflow {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for rule in &self.rules {
+            write!(f, "{rule},")?;
+        }
+        write!(f, "{}}}", self.default)
+    }
+
+}
+
+impl Display for Rule {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}:{}", self.field, self.op, self.value, self.target)
+    }
+
+}
+
+impl Display for Op {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::LessThan => write!(f, "<"),
+            Op::GreaterThan => write!(f, ">")
+        }
+    }
+
+}
+
+impl MachinePartRanges {
+
+    fn get(&self, field: char) -> &RangeInclusive<u32> {
+        match field {
+            'x' => &self.x,
+            'm' => &self.m,
+            'a' => &self.a,
+            's' => &self.s,
+            _ => unreachable!("Unexpected field: {field}")
+        }
+    }
+
+    fn with(&self, field: char, range: RangeInclusive<u32>) -> Option<MachinePartRanges> {
+        if range.is_empty() {
+            return None;
+        }
+
+        let mut parts = self.clone();
+        match field {
+            'x' => parts.x = range,
+            'm' => parts.m = range,
+            'a' => parts.a = range,
+            's' => parts.s = range,
+            _ => unreachable!("Unexpected field: {field}")
+        }
+        Some(parts)
+    }
+
+    // Splits into the parts matching the rule and those that don't, either of which may be empty
+    fn split(&self, rule: &Rule) -> (Option<MachinePartRanges>, Option<MachinePartRanges>) {
+        let (start, end) = self.get(rule.field).clone().into_inner();
+        let (matching, remaining) = match rule.op {
+            Op::LessThan => (start ..= end.min(rule.value.saturating_sub(1)), start.max(rule.value) ..= end),
+            Op::GreaterThan => (start.max(rule.value.saturating_add(1)) ..= end, start ..= end.min(rule.value))
+        };
+
+        (self.with(rule.field, matching), self.with(rule.field, remaining))
+    }
+
+    fn count(&self) -> u64 {
+        // Ranges are never empty, `with` discards those
+        [&self.x, &self.m, &self.a, &self.s]
+            .into_iter()
+            .map(|r| (r.end() - r.start() + 1) as u64)
+            .product()
+    }
+
+}
+
+impl MachinePart {
+
+    fn parse(line: &str) -> Result<MachinePart> {
+        let part_captures = PART_REGEX
+            .captures(line)
+            .ok_or_else(|| Error::parse(line, "expected part ratings like {x=787,m=2655,a=1222,s=2876}"))?;
+        Ok(MachinePart {
+            x: part_captures["x"].try_parse()?,
+            m: part_captures["m"].try_parse()?,
+            a: part_captures["a"].try_parse()?,
+            s: part_captures["s"].try_parse()?
+        })
+    }
+
+    fn get(&self, field: char) -> u32 {
+        match field {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => unreachable!("Unexpected field: {field}")
+        }
+    }
+
+    fn value(&self) -> u32 {
+        self.x + self.m + self.a + self.s
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,296 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::io::Write;
+use std::rc::Rc;
+use log::{debug, trace};
+use num::integer::lcm;
+use crate::{Error, Result, Solution, str::StringOps};
+use crate::dot::{Digraph, ToDot};
+
+const MAX_PRESSES_TO_FIND_PERIODS: u64 = 100_000;
+
+/// Where the pulses sent while solving are written to. Being shared, the networks parsed
+/// for each part can write to the same one without overwriting each other.
+pub type EventLog = Rc<RefCell<dyn Write>>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    // Only ever receives pulses, like `rx`
+    Output
+}
+
+#[derive(Debug)]
+struct Module {
+    name: String,
+    kind: ModuleKind,
+    destinations: Vec<usize>,
+    inputs: Vec<usize>
+}
+
+/// A pulse sent by one module to another, in the order the modules process them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Pulse<'a> {
+    pub from: &'a str,
+    pub high: bool,
+    pub to: &'a str
+}
+
+/// What the modules remember between pulses, which is all that changes from one button press to the next.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NetworkState {
+    // Indexed by module, only meaningful for flip-flops
+    flip_flops_on: Vec<bool>,
+    // Indexed by module and then by input, the last pulse each conjunction got from each input
+    conjunction_memory: Vec<Vec<bool>>
+}
+
+pub struct ModuleNetwork {
+    modules: Vec<Module>,
+    indices: HashMap<String, usize>,
+    event_log: Option<EventLog>
+}
+
+impl Solution for ModuleNetwork {
+
+    fn parse(content: &str) -> Result<ModuleNetwork> {
+        let mut network = ModuleNetwork { modules: vec![], indices: HashMap::new(), event_log: None };
+
+        let mut destination_names = vec![];
+        for line in content.lines() {
+            let (name_str, dest_str) = line.try_split_once(" -> ")?;
+            let (name, kind) = match name_str.chars().next() {
+                _ if name_str == "broadcaster" => (name_str, ModuleKind::Broadcaster),
+                Some('%') => (&name_str[1..], ModuleKind::FlipFlop),
+                Some('&') => (&name_str[1..], ModuleKind::Conjunction),
+                _ => return Err(Error::parse(name_str, "expected broadcaster or a module name prefixed by % or &"))
+            };
+
+            let index = network.add_module(name);
+            if network.modules[index].kind != ModuleKind::Output {
+                return Err(Error::parse(name, "duplicate module"));
+            }
+            network.modules[index].kind = kind;
+            destination_names.push((index, dest_str.split(", ").collect::<Vec<_>>()));
+        }
+
+        for (index, names) in destination_names {
+            for name in names {
+                let dest = network.add_module(name);
+                network.modules[index].destinations.push(dest);
+                network.modules[dest].inputs.push(index);
+            }
+        }
+
+        if !network.indices.contains_key("broadcaster") {
+            return Err("Expected a broadcaster module".into());
+        }
+
+        Ok(network)
+    }
+
+    fn part1(&self) -> Result<String> {
+        self.log_event("# part 1")?;
+
+        // Should the modules go back to an earlier state, the same pulses will follow from then on
+        let mut state = self.initial_state();
+        let mut seen_states = HashMap::new();
+        let mut pulse_counts: Vec<(u64, u64)> = vec![];
+        for press in 0 .. 1000_usize {
+            if let Some(&cycle_start) = seen_states.get(&state) {
+                debug!(target: "day20", "Press {press} repeats the state from press {cycle_start}");
+                let cycle_len = press - cycle_start;
+                for i in press .. 1000 {
+                    pulse_counts.push(pulse_counts[i - cycle_len]);
+                }
+                break;
+            }
+            seen_states.insert(state.clone(), press);
+
+            let pulses = self.press_button(&mut state);
+            self.log_pulses(press as u64 + 1, &pulses)?;
+
+            let num_high = pulses.iter().filter(|p| p.high).count() as u64;
+            pulse_counts.push((pulses.len() as u64 - num_high, num_high));
+        }
+
+        let total_low_pulses: u64 = pulse_counts.iter().map(|(low, _)| low).sum();
+        let total_high_pulses: u64 = pulse_counts.iter().map(|(_, high)| high).sum();
+        debug!(target: "day20", "low={total_low_pulses}, high={total_high_pulses}");
+        Ok((total_low_pulses * total_high_pulses).to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        // Assumes `rx` is fed by a single conjunction, which only sends it a low pulse once all of its
+        // inputs sent a high one. Each input is expected to do so periodically, every `n` presses,
+        // so they first line up on the least common multiple of their periods.
+        let rx_feeders = self.indices
+            .get("rx")
+            .map(|&rx| self.modules[rx].inputs.as_slice())
+            .unwrap_or_default();
+        let &[feeder] = rx_feeders else {
+            return Err(format!("Expected a single module sending pulses to rx, found {}", rx_feeders.len()).into());
+        };
+        let feeder = &self.modules[feeder];
+        if feeder.kind != ModuleKind::Conjunction {
+            return Err(format!("Expected {}, which sends pulses to rx, to be a conjunction", feeder.name).into());
+        }
+
+        self.log_event("# part 2")?;
+
+        let mut state = self.initial_state();
+        let mut high_presses: HashMap<&str, Vec<u64>> = HashMap::new();
+        let mut presses = 0_u64;
+        while high_presses.len() < feeder.inputs.len() || high_presses.values().any(|ps| ps.len() < 2) {
+            if presses == MAX_PRESSES_TO_FIND_PERIODS {
+                return Err(format!("Not every input of {} sent it two high pulses within {presses} presses", feeder.name).into());
+            }
+
+            presses += 1;
+            let pulses = self.press_button(&mut state);
+            self.log_pulses(presses, &pulses)?;
+            for pulse in pulses {
+                if pulse.high && pulse.to == feeder.name {
+                    high_presses.entry(pulse.from).or_default().push(presses);
+                }
+            }
+        }
+
+        let mut periods = vec![];
+        for (input, presses) in high_presses {
+            let period = presses[0];
+            if presses[1] != 2 * period {
+                return Err(format!("Expected {input} to send high pulses to {} every {period} presses, but got one at {}", feeder.name, presses[1]).into());
+            }
+            debug!(target: "day20", "{input} sends a high pulse to {} every {period} presses", feeder.name);
+            periods.push(period);
+        }
+
+        Ok(periods.into_iter().fold(1, lcm).to_string())
+    }
+
+}
+
+impl ModuleNetwork {
+
+    /// Writes every pulse sent while solving to the given log, one per line, prefixed by the button press.
+    pub fn with_event_log(self, event_log: EventLog) -> ModuleNetwork {
+        ModuleNetwork { event_log: Some(event_log), ..self }
+    }
+
+    /// All flip-flops off and all conjunctions remembering a low pulse from each input.
+    pub fn initial_state(&self) -> NetworkState {
+        NetworkState {
+            flip_flops_on: vec![false; self.modules.len()],
+            conjunction_memory: self.modules
+                .iter()
+                .map(|m| match m.kind {
+                    ModuleKind::Conjunction => vec![false; m.inputs.len()],
+                    _ => vec![]
+                })
+                .collect()
+        }
+    }
+
+    /// Sends a low pulse to the broadcaster and processes every pulse until they settle,
+    /// giving back all of them in the order they were sent.
+    pub fn press_button(&self, state: &mut NetworkState) -> Vec<Pulse<'_>> {
+        let broadcaster = self.indices["broadcaster"];
+        let mut pulses = vec![Pulse { from: "button", high: false, to: &self.modules[broadcaster].name }];
+        trace!(target: "day20::pulses", "{}", pulses[0]);
+
+        let mut pulse_queue = VecDeque::from([(None, false, broadcaster)]);
+        while let Some((origin, high, curr)) = pulse_queue.pop_front() {
+            let module = &self.modules[curr];
+            let new_pulse = match module.kind {
+                ModuleKind::Broadcaster => Some(high),
+                ModuleKind::FlipFlop if !high => {
+                    let is_on = &mut state.flip_flops_on[curr];
+                    *is_on = !*is_on;
+                    Some(*is_on)
+                },
+                ModuleKind::Conjunction => {
+                    let input = module.inputs.iter().position(|&i| Some(i) == origin).unwrap();
+                    let memory = &mut state.conjunction_memory[curr];
+                    memory[input] = high;
+                    Some(!memory.iter().all(|h| *h))
+                },
+                ModuleKind::FlipFlop | ModuleKind::Output => None
+            };
+
+            if let Some(new_pulse) = new_pulse {
+                for &dest in &module.destinations {
+                    let pulse = Pulse { from: &module.name, high: new_pulse, to: &self.modules[dest].name };
+                    trace!(target: "day20::pulses", "{pulse}");
+                    pulses.push(pulse);
+                    pulse_queue.push_back((Some(curr), new_pulse, dest));
+                }
+            }
+        }
+
+        pulses
+    }
+
+    // Gives the index of the module, adding it as an output if it wasn't known yet
+    fn add_module(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+
+        self.modules.push(Module { name: name.to_string(), kind: ModuleKind::Output, destinations: vec![], inputs: vec![] });
+        self.indices.insert(name.to_string(), self.modules.len() - 1);
+        self.modules.len() - 1
+    }
+
+    fn log_event(&self, event: &str) -> Result<()> {
+        if let Some(event_log) = &self.event_log {
+            writeln!(event_log.borrow_mut(), "{event}")?;
+        }
+        Ok(())
+    }
+
+    fn log_pulses(&self, press: u64, pulses: &[Pulse]) -> Result<()> {
+        if let Some(event_log) = &self.event_log {
+            let mut event_log = event_log.borrow_mut();
+            for pulse in pulses {
+                writeln!(event_log, "{press} {pulse}")?;
+            }
+            event_log.flush()?;
+        }
+        Ok(())
+    }
+
+}
+
+// Modules are labelled as in the input, with their type prefix
+impl ToDot for ModuleNetwork {
+
+    fn to_dot(&self) -> Digraph {
+        let mut graph = Digraph::new("modules");
+        for module in &self.modules {
+            let (prefix, shape) = match module.kind {
+                ModuleKind::Broadcaster => ("", "house"),
+                ModuleKind::FlipFlop => ("%", "box"),
+                ModuleKind::Conjunction => ("&", "invtriangle"),
+                ModuleKind::Output => ("", "doublecircle")
+            };
+            graph.node(&module.name, &format!("{prefix}{}", module.name), shape);
+            for &dest in &module.destinations {
+                graph.edge(&module.name, &self.modules[dest].name, None);
+            }
+        }
+        graph
+    }
+
+}
+
+impl Display for Pulse<'_> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -{}-> {}", self.from, if self.high { "high" } else { "low" }, self.to)
+    }
+
+}
@@ -0,0 +1,149 @@
+use std::collections::{HashSet, VecDeque};
+use log::debug;
+use crate::{Result, Solution};
+use crate::grid::Grid;
+
+const PART1_STEPS: u64 = 64;
+const PART2_STEPS: u64 = 26501365;
+
+// Beyond this, walking the infinite garden step by step gets too slow
+const MAX_BRUTE_FORCE_STEPS: u64 = 1000;
+
+pub struct Garden {
+    tiles: Grid<char>,
+    // Fewest steps from S to each plot, if it can be reached at all
+    distances: Grid<Option<u64>>,
+    steps: Vec<u64>
+}
+
+impl Solution for Garden {
+
+    fn parse(content: &str) -> Result<Garden> {
+        let tiles = Grid::parse(content, Ok)?;
+        let start = tiles.position(|&t| t == 'S').ok_or("Expected a starting position S")?;
+        let distances = distances_from(&tiles, start);
+
+        Ok(Garden { tiles, distances, steps: vec![] })
+    }
+
+    fn part1(&self) -> Result<String> {
+        self.answer_for_each_steps(PART1_STEPS, |num_steps| Ok(self.count_reachable_plots(num_steps)))
+    }
+
+    fn part2(&self) -> Result<String> {
+        self.answer_for_each_steps(PART2_STEPS, |num_steps| {
+            if num_steps <= MAX_BRUTE_FORCE_STEPS {
+                return Ok(self.count_reachable_plots_on_infinite_grid(num_steps));
+            }
+            self.extrapolate_reachable_plots_on_infinite_grid(num_steps)
+        })
+    }
+
+}
+
+impl Garden {
+
+    /// Overrides the numbers of steps walked, which otherwise are 64 in part 1 and 26501365 in part 2.
+    /// With more than one, each is answered as `steps: plots`.
+    pub fn with_steps(self, steps: Vec<u64>) -> Garden {
+        Garden { steps, ..self }
+    }
+
+    fn answer_for_each_steps(&self, default_steps: u64, count: impl Fn(u64) -> Result<u64>) -> Result<String> {
+        match &self.steps[..] {
+            [] => Ok(count(default_steps)?.to_string()),
+            [num_steps] => Ok(count(*num_steps)?.to_string()),
+            steps => Ok(steps
+                .iter()
+                .map(|&num_steps| Ok(format!("{num_steps}: {}", count(num_steps)?)))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "))
+        }
+    }
+
+    /// Counts the plots reachable in exactly `num_steps` without leaving the garden. Those are the ones
+    /// at most that far away and at a distance with the same parity, as one can always step back and forth.
+    pub fn count_reachable_plots(&self, num_steps: u64) -> u64 {
+        self.distances
+            .iter()
+            .flatten()
+            .filter(|&&d| d <= num_steps && d % 2 == num_steps % 2)
+            .count() as u64
+    }
+
+    /// Counts the plots reachable in exactly `num_steps` when the garden repeats infinitely in every direction,
+    /// by walking it step by step.
+    pub fn count_reachable_plots_on_infinite_grid(&self, num_steps: u64) -> u64 {
+        let (start_r, start_c) = self.tiles.position(|&t| t == 'S').unwrap();
+        let num_rows = self.tiles.num_rows() as i64;
+        let num_cols = self.tiles.num_cols() as i64;
+        let is_plot = |r: i64, c: i64| self.tiles[r.rem_euclid(num_rows) as usize][c.rem_euclid(num_cols) as usize] != '#';
+
+        // Same as within the garden, count the plots at each distance with the same parity. But as it's
+        // unbounded, only keep the latest distances. Being a grid, the plots at the next distance can only
+        // neighbor those at the current or previous one.
+        let mut prev_plots = HashSet::new();
+        let mut curr_plots = HashSet::from([(start_r as i64, start_c as i64)]);
+        let mut num_reachable = 0;
+        for distance in 0 ..= num_steps {
+            if distance % 2 == num_steps % 2 {
+                num_reachable += curr_plots.len() as u64;
+            }
+
+            let next_plots: HashSet<_> = curr_plots
+                .iter()
+                .flat_map(|&(r, c)| [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)])
+                .filter(|&(r, c)| is_plot(r, c) && !prev_plots.contains(&(r, c)))
+                .collect();
+            prev_plots = std::mem::replace(&mut curr_plots, next_plots);
+        }
+
+        num_reachable
+    }
+
+    /// Counts the plots reachable in exactly `num_steps` when the garden repeats infinitely in every direction,
+    /// without walking them all. Relies on the garden being a square with `S` at its center and with
+    /// no rocks on its row and column, so that each additional garden walked across adds a ring of
+    /// gardens reached in the same way. The count then grows quadratically with the gardens walked across,
+    /// and can be extrapolated from walking the first few.
+    pub fn extrapolate_reachable_plots_on_infinite_grid(&self, num_steps: u64) -> Result<u64> {
+        let size = self.tiles.num_rows();
+        let (start_r, start_c) = self.tiles.position(|&t| t == 'S').unwrap();
+        if size != self.tiles.num_cols() || start_r != size / 2 || start_c != size / 2 {
+            return Err("Extrapolating requires a square garden with S at its center".into());
+        }
+        if self.tiles.row(start_r).contains(&'#') || self.tiles.col(start_c).any(|t| *t == '#') {
+            return Err("Extrapolating requires no rocks in the row and column of S".into());
+        }
+
+        let size = size as u64;
+        let num_gardens = num_steps / size;
+        let remaining_steps = num_steps % size;
+        let [f0, f1, f2] = [0, 1, 2].map(|n| self.count_reachable_plots_on_infinite_grid(remaining_steps + n * size) as i64);
+        debug!(target: "day21", "Reachable plots after {remaining_steps} + n * {size} steps, for n up to 2: {f0}, {f1}, {f2}");
+
+        // Newton's forward differences of the quadratic through the first three counts
+        let n = num_gardens as i64;
+        let reachable = f0 + n * (f1 - f0) + n * (n - 1) / 2 * (f2 - 2 * f1 + f0);
+        Ok(reachable as u64)
+    }
+
+}
+
+// Breadth-first from the start, so each plot is first reached through one of the shortest paths
+fn distances_from(tiles: &Grid<char>, (start_r, start_c): (usize, usize)) -> Grid<Option<u64>> {
+    let mut distances = Grid::new(tiles.num_rows(), tiles.num_cols(), None);
+    distances[start_r][start_c] = Some(0);
+
+    let mut to_visit = VecDeque::from([(start_r, start_c, 0)]);
+    while let Some((r, c, distance)) = to_visit.pop_front() {
+        for (nr, nc) in tiles.neighbors4(r, c) {
+            if tiles[nr][nc] != '#' && distances[nr][nc].is_none() {
+                distances[nr][nc] = Some(distance + 1);
+                to_visit.push_back((nr, nc, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
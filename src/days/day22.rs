@@ -0,0 +1,330 @@
+#[cfg(feature = "viz")]
+use kiss3d::light::Light;
+#[cfg(feature = "viz")]
+use kiss3d::nalgebra::{Translation3, UnitQuaternion, Vector3};
+#[cfg(feature = "viz")]
+use kiss3d::window::Window;
+use log::{Level, log_enabled, debug, trace};
+use std::fmt::Display;
+use std::collections::{HashMap, HashSet};
+use crate::{Error, Result, Solution, str::StringOps};
+use crate::grid::Grid;
+
+#[derive(PartialEq, Hash, Eq)]
+struct Point {
+    x: u16,
+    y: u16,
+    z: u16
+}
+
+#[derive(PartialEq, Hash, Eq)]
+struct Brick {
+    label: String,
+    bottom_left: Point,
+    top_right: Point
+}
+
+pub struct SandStack {
+    bricks: Vec<Brick>,
+    brick_dependents: HashMap<usize, HashSet<usize>>,
+    brick_dependencies: HashMap<usize, HashSet<usize>>,
+    render: bool
+}
+
+impl Solution for SandStack {
+
+    fn parse(content: &str) -> Result<SandStack> {
+        let mut bricks: Vec<Brick> = content
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Brick::parse(i, l))
+            .collect::<Result<_>>()?;
+
+        // Settle them bottom up, so that each brick only has to look at those already settled below. For that,
+        // keep track of the top brick over each (x, y), which it'll rest on if it's the highest under its footprint.
+        bricks.sort_by_key(|b| b.bottom_left.z);
+        let max_x = bricks.iter().map(|b| b.top_right.x as usize).max().unwrap_or(0);
+        let max_y = bricks.iter().map(|b| b.top_right.y as usize).max().unwrap_or(0);
+        let mut top_bricks: Grid<Option<usize>> = Grid::new(max_x + 1, max_y + 1, None);
+
+        let mut brick_dependents = HashMap::new();
+        let mut brick_dependencies = HashMap::new();
+
+        for i in 0 .. bricks.len() {
+            let bricks_below: HashSet<usize> = bricks[i]
+                .footprint()
+                .filter_map(|(x, y)| top_bricks[x][y])
+                .collect();
+            let rest_z = bricks_below.iter().map(|&j| bricks[j].top_right.z + 1).max().unwrap_or(1);
+
+            let dz = bricks[i].top_right.z - bricks[i].bottom_left.z;
+            bricks[i].bottom_left.z = rest_z;
+            bricks[i].top_right.z = rest_z + dz;
+            trace!(target: "day22::settling", "Settled {}", bricks[i]);
+
+            for j in bricks_below.into_iter().filter(|&j| bricks[j].top_right.z + 1 == rest_z) {
+                brick_dependents
+                    .entry(j)
+                    .or_insert(HashSet::new())
+                    .insert(i);
+
+                brick_dependencies
+                    .entry(i)
+                    .or_insert(HashSet::new())
+                    .insert(j);
+            }
+
+            for (x, y) in bricks[i].footprint() {
+                top_bricks[x][y] = Some(i);
+            }
+        }
+
+        if log_enabled!(target: "day22::dependencies", Level::Debug) {
+            let format_links = |links: &HashMap<usize, HashSet<usize>>| links
+                .iter()
+                .map(|(k, v)| format!("{}: [{}]",
+                    bricks[*k].label,
+                    v.iter().map(|j| bricks[*j].label.clone()).collect::<Vec<_>>().join(", ")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            debug!(target: "day22::dependencies", "Dependents: {{{}}}", format_links(&brick_dependents));
+            debug!(target: "day22::dependencies", "Dependencies: {{{}}}", format_links(&brick_dependencies));
+        }
+
+        Ok(SandStack { bricks, brick_dependents, brick_dependencies, render: false })
+    }
+
+    fn part1(&self) -> Result<String> {
+        let mut safely_removable_bricks = vec![];
+        for i in 0 .. self.bricks.len() {
+            let is_safe = self.brick_dependents
+                .get(&i)
+                .unwrap_or(&HashSet::new())
+                .iter()
+                .all(|j| self.brick_dependencies
+                    .get(j)
+                    .unwrap()
+                    .len() > 1
+                );
+            if is_safe {
+                safely_removable_bricks.push(i);
+            }
+        }
+        debug!(target: "day22", "Safely removable: {safely_removable_bricks:?}");
+        if self.render {
+            self.render(safely_removable_bricks.iter().map(|i| &self.bricks[*i]).collect())?;
+        }
+        Ok(safely_removable_bricks.len().to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        let falling_bricks = self.count_falling_bricks();
+        for (label, count) in &falling_bricks {
+            debug!(target: "day22::chain_reactions", "Disintegrating {label} makes {count} other bricks fall");
+        }
+        Ok(falling_bricks.iter().map(|(_, count)| count).sum::<usize>().to_string())
+    }
+
+}
+
+impl SandStack {
+
+    /// Shows the settled bricks in a window when solving part 1, highlighting those safe to disintegrate.
+    /// Blocks until the window is closed.
+    pub fn with_rendering(self) -> SandStack {
+        SandStack { render: true, ..self }
+    }
+
+    #[cfg(feature = "viz")]
+    fn render(&self, highlights: HashSet<&Brick>) -> Result<()> {
+        render_bricks(&self.bricks, highlights);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "viz"))]
+    fn render(&self, _highlights: HashSet<&Brick>) -> Result<()> {
+        Err("Rendering requires building with the viz feature".into())
+    }
+
+    /// The settled bricks, one per line in the same syntax as the input.
+    pub fn to_text(&self) -> String {
+        self.bricks
+            .iter()
+            .map(|b| format!("{}~{}\n", b.bottom_left.to_input(), b.top_right.to_input()))
+            .collect()
+    }
+
+    /// The settled bricks as a Wavefront OBJ mesh, with a box object per brick.
+    pub fn to_obj(&self) -> String {
+        let mut obj = String::new();
+        for (i, b) in self.bricks.iter().enumerate() {
+            obj.push_str(&format!("o {}\n", b.label));
+
+            // The brick covers whole cubes, so its far corner is one past the top right one
+            let xs = [b.bottom_left.x, b.top_right.x + 1];
+            let ys = [b.bottom_left.y, b.top_right.y + 1];
+            let zs = [b.bottom_left.z, b.top_right.z + 1];
+            for x in xs {
+                for y in ys {
+                    for z in zs {
+                        obj.push_str(&format!("v {x} {y} {z}\n"));
+                    }
+                }
+            }
+
+            // Vertices are 1-based across the whole file, numbered here by their (x, y, z) bits
+            let v = |x: usize, y: usize, z: usize| 8 * i + 4 * x + 2 * y + z + 1;
+            for [a, b, c, d] in [
+                [v(0, 0, 0), v(0, 1, 0), v(1, 1, 0), v(1, 0, 0)],
+                [v(0, 0, 1), v(1, 0, 1), v(1, 1, 1), v(0, 1, 1)],
+                [v(0, 0, 0), v(1, 0, 0), v(1, 0, 1), v(0, 0, 1)],
+                [v(0, 1, 0), v(0, 1, 1), v(1, 1, 1), v(1, 1, 0)],
+                [v(0, 0, 0), v(0, 0, 1), v(0, 1, 1), v(0, 1, 0)],
+                [v(1, 0, 0), v(1, 1, 0), v(1, 1, 1), v(1, 0, 1)],
+            ] {
+                obj.push_str(&format!("f {a} {b} {c} {d}\n"));
+            }
+        }
+        obj
+    }
+
+    /// For each brick, by label, how many others would fall if it were disintegrated.
+    pub fn count_falling_bricks(&self) -> Vec<(&str, usize)> {
+        (0 .. self.bricks.len())
+            .map(|i| (self.bricks[i].label.as_str(), self.count_falling_bricks_without(i)))
+            .collect()
+    }
+
+    // A brick falls once all the bricks it rests on have, so count down its fallen supports
+    fn count_falling_bricks_without(&self, disintegrated: usize) -> usize {
+        let mut remaining_supports = HashMap::new();
+        let mut falling = vec![disintegrated];
+        let mut num_fallen = 0;
+        while let Some(i) = falling.pop() {
+            for &j in self.brick_dependents.get(&i).into_iter().flatten() {
+                let supports = remaining_supports
+                    .entry(j)
+                    .or_insert_with(|| self.brick_dependencies[&j].len());
+                *supports -= 1;
+                if *supports == 0 {
+                    num_fallen += 1;
+                    falling.push(j);
+                }
+            }
+        }
+
+        num_fallen
+    }
+
+}
+
+#[cfg(feature = "viz")]
+fn render_bricks(bricks: &[Brick], highlights: HashSet<&Brick>) {
+    let mut window = Window::new("Map");
+    window.set_background_color(1.0, 1.0, 1.0);
+    window.scene_mut().append_rotation(&UnitQuaternion::new(Vector3::z() * -std::f32::consts::FRAC_PI_2));
+    window.scene_mut().append_rotation(&UnitQuaternion::new(Vector3::x() * -std::f32::consts::FRAC_PI_2));
+    window.scene_mut().append_translation(&Translation3::new(0.0, -2.0, 2.0));
+    
+    let mut cubes = vec![];
+    for (i, b) in bricks.iter().enumerate() {
+        let x_len = (b.top_right.x - b.bottom_left.x + 1) as f32;
+        let y_len = (b.top_right.y - b.bottom_left.y + 1) as f32;
+        let z_len = (b.top_right.z - b.bottom_left.z + 1) as f32;
+        let mut c = window.add_cube(
+            x_len,
+            y_len,
+            z_len
+        );
+        c.append_translation(&Translation3::new(x_len / 2.0, y_len / 2.0, z_len / 2.0));
+        
+        c.append_translation(&Translation3::new(
+            b.bottom_left.x as f32,
+            b.bottom_left.y as f32,
+            b.bottom_left.z as f32
+        ));
+
+        if highlights.contains(&b) {
+            c.set_color(0.5, 0.5, 0.5);
+        } else if i % 3 == 0 {
+            c.set_color((0.1 + i as f32) / (0.1 + bricks.len() as f32), (i % 100) as f32 / 100.0, 0.0);
+        } else if i % 3 == 1 {
+            c.set_color(0.0, (0.1 + i as f32) / (0.1 + bricks.len() as f32), (i % 100) as f32 / 100.0);
+        } else {
+            c.set_color((i % 100) as f32 / 100.0, 0.0, (0.1 + i as f32) / (0.1 + bricks.len() as f32));
+        }
+
+        cubes.push(c);
+    }
+    
+    window.set_light(Light::StickToCamera);
+    while window.render() { }
+}
+
+impl Brick {
+
+    fn parse(i: usize, line: &str) -> Result<Brick> {
+        let (p1_str, p2_str) = line.try_split_once("~")?;
+        let bottom_left = Point::parse(p1_str)?;
+        let top_right = Point::parse(p2_str)?;
+        if bottom_left.x > top_right.x || bottom_left.y > top_right.y || bottom_left.z > top_right.z {
+            return Err(Error::parse(p2_str, "expected the second corner to be at or beyond the first one"));
+        }
+        if bottom_left.z == 0 {
+            return Err(Error::parse(p1_str, "expected bricks to be above the ground, at z >= 1"));
+        }
+
+        Ok(Brick { label: label(i), bottom_left, top_right })
+    }
+
+    // The (x, y) of every cube in the brick, as seen from above
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> {
+        let ys = self.bottom_left.y ..= self.top_right.y;
+        (self.bottom_left.x ..= self.top_right.x)
+            .flat_map(move |x| ys.clone().map(move |y| (x as usize, y as usize)))
+    }
+
+}
+
+// Like spreadsheet columns: A to Z, then AA to ZZ, then AAA and so on
+fn label(i: usize) -> String {
+    let mut label = vec![];
+    let mut n = i + 1;
+    while n > 0 {
+        n -= 1;
+        label.push(char::from(b'A' + (n % 26) as u8));
+        n /= 26;
+    }
+    label.iter().rev().collect()
+}
+
+impl Display for Brick {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}: [{}, {}]", self.label, self.bottom_left, self.top_right))
+    }
+
+}
+
+impl Point {
+
+    fn parse(s: &str) -> Result<Point> {
+        match s.try_parse_list(",")?[..] {
+            [x, y, z] => Ok(Point { x, y, z }),
+            _ => Err(Error::parse(s, "expected coordinates like 1,0,1"))
+        }
+    }
+
+    fn to_input(&self) -> String {
+        format!("{},{},{}", self.x, self.y, self.z)
+    }
+
+}
+
+impl Display for Point {
+    
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("({}, {}, {})", self.x, self.y, self.z))
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+
+use crate::{Result, Solution};
+
+/// An implemented day, able to parse its input into a type-erased [`Solution`].
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<Box<dyn Solution>>
+}
+
+/// All the implemented days, in order.
+pub const DAYS: [Day; 22] = [
+    Day { number: 1, parse: parse_boxed::<day01::CalibrationDocument> },
+    Day { number: 2, parse: parse_boxed::<day02::GameRecord> },
+    Day { number: 3, parse: parse_boxed::<day03::EngineSchematic> },
+    Day { number: 4, parse: parse_boxed::<day04::ScratchCardPile> },
+    Day { number: 5, parse: parse_boxed::<day05::Almanac> },
+    Day { number: 6, parse: parse_boxed::<day06::RaceSheet> },
+    Day { number: 7, parse: parse_boxed::<day07::CamelCardsGame> },
+    Day { number: 8, parse: parse_boxed::<day08::Documents> },
+    Day { number: 9, parse: parse_boxed::<day09::OasisReport> },
+    Day { number: 10, parse: parse_boxed::<day10::PipeMap> },
+    Day { number: 11, parse: parse_boxed::<day11::SpaceImage> },
+    Day { number: 12, parse: parse_boxed::<day12::Field> },
+    Day { number: 13, parse: parse_boxed::<day13::Notes> },
+    Day { number: 14, parse: parse_boxed::<day14::Platform> },
+    Day { number: 15, parse: parse_boxed::<day15::InitSequence> },
+    Day { number: 16, parse: parse_boxed::<day16::Contraption> },
+    Day { number: 17, parse: parse_boxed::<day17::HeatMap> },
+    Day { number: 18, parse: parse_boxed::<day18::DigPlan> },
+    Day { number: 19, parse: parse_boxed::<day19::PartSorter> },
+    Day { number: 20, parse: parse_boxed::<day20::ModuleNetwork> },
+    Day { number: 21, parse: parse_boxed::<day21::Garden> },
+    Day { number: 22, parse: parse_boxed::<day22::SandStack> },
+];
+
+impl Day {
+
+    pub fn parse(&self, content: &str) -> Result<Box<dyn Solution>> {
+        (self.parse)(content)
+    }
+
+}
+
+/// Finds the implemented day with the given number, if any.
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+fn parse_boxed<S: Solution + 'static>(content: &str) -> Result<Box<dyn Solution>> {
+    Ok(Box::new(S::parse(content)?))
+}
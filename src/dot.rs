@@ -0,0 +1,61 @@
+use std::fmt::{self, Display};
+use crate::Result;
+use crate::Solution;
+use crate::days::{day08, day19, day20};
+
+/// Puzzles whose input describes a graph, which can be exported as Graphviz DOT to look at its structure.
+pub trait ToDot {
+    fn to_dot(&self) -> Digraph;
+}
+
+/// A directed graph in the DOT language, rendered with e.g. `dot -Tsvg`.
+pub struct Digraph {
+    name: String,
+    lines: Vec<String>
+}
+
+impl Digraph {
+
+    pub fn new(name: &str) -> Digraph {
+        Digraph { name: name.to_string(), lines: vec![] }
+    }
+
+    pub fn node(&mut self, id: &str, label: &str, shape: &str) {
+        self.lines.push(format!("{} [label={}, shape={shape}]", quote(id), quote(label)));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, label: Option<&str>) {
+        let mut line = format!("{} -> {}", quote(from), quote(to));
+        if let Some(label) = label {
+            line.push_str(&format!(" [label={}]", quote(label)));
+        }
+        self.lines.push(line);
+    }
+
+}
+
+impl Display for Digraph {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {} {{", quote(&self.name))?;
+        for line in &self.lines {
+            writeln!(f, "    {line};")?;
+        }
+        writeln!(f, "}}")
+    }
+
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Parses the input of the given day and exports it as DOT, if it's one of the graph-shaped ones.
+pub fn graph(day: u8, content: &str) -> Result<Digraph> {
+    match day {
+        8 => Ok(day08::Documents::parse(content)?.to_dot()),
+        19 => Ok(day19::PartSorter::parse(content)?.to_dot()),
+        20 => Ok(day20::ModuleNetwork::parse(content)?.to_dot()),
+        _ => Err(format!("Day {day} has no graph to export").into())
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::{self, Display};
+use std::io;
+
+/// Everything that can go wrong while reading, parsing and solving a puzzle input.
+pub enum Error {
+    /// The input is malformed, see [`ParseError`] for where
+    Parse(ParseError),
+    /// The input file could not be read
+    Io(io::Error),
+    /// Any other failure, e.g. the input not meeting an assumption of the solution
+    Other(String),
+}
+
+/// A malformed token found in the input.
+///
+/// The line and column are unknown when the error is raised deep inside a parser,
+/// and are filled in by [`Error::locate`] once the whole content is at hand.
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub token: String,
+    pub file: Option<String>,
+    pub location: Option<Location>,
+    // Address of the token, to find it within the content it was sliced from
+    token_addr: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize
+}
+
+impl Error {
+
+    /// Creates a parse error for `token`, which should be a slice of the content
+    /// being parsed so that it can be located later on.
+    pub fn parse(token: &str, message: impl Into<String>) -> Error {
+        Error::Parse(ParseError {
+            message: message.into(),
+            token: token.to_string(),
+            file: None,
+            location: None,
+            token_addr: token.as_ptr() as usize
+        })
+    }
+
+    /// Turns this into a parse error pointing at `token`, keeping its message.
+    pub fn at(self, token: &str) -> Error {
+        let message = match self {
+            Error::Parse(err) => err.message,
+            err => err.to_string()
+        };
+        Error::parse(token, message)
+    }
+
+    /// Fills in the line and column of a parse error if its token was sliced from `content`.
+    pub fn locate(self, content: &str) -> Error {
+        match self {
+            Error::Parse(mut err) if err.location.is_none() => {
+                err.location = Location::of(err.token_addr, content);
+                Error::Parse(err)
+            },
+            _ => self
+        }
+    }
+
+    /// Records the file the failing input came from.
+    pub fn in_file(self, file_path: &str) -> Error {
+        match self {
+            Error::Parse(mut err) => {
+                err.file.get_or_insert_with(|| file_path.to_string());
+                Error::Parse(err)
+            },
+            _ => self
+        }
+    }
+
+}
+
+impl Location {
+
+    fn of(addr: usize, content: &str) -> Option<Location> {
+        let start = content.as_ptr() as usize;
+        if addr < start || addr > start + content.len() {
+            return None;
+        }
+
+        let before = &content[.. addr - start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start ..].chars().count() + 1
+        })
+    }
+
+}
+
+impl Display for Error {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Other(msg) => write!(f, "{msg}")
+        }
+    }
+
+}
+
+impl Display for ParseError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Mimic the "file:line:column: message" format of compilers, so that editors can jump to it
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        if let Some(Location { line, column }) = self.location {
+            write!(f, "{line}:{column}:")?;
+        }
+        if self.file.is_some() || self.location.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{} at \"{}\"", self.message, self.token)
+    }
+
+}
+
+// Returning an error from `main` prints its Debug form, so make it as readable as Display
+impl fmt::Debug for Error {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+
+}
+
+impl std::error::Error for Error {}
+
+impl From<&str> for Error {
+
+    fn from(msg: &str) -> Error {
+        Error::Other(msg.to_string())
+    }
+
+}
+
+impl From<String> for Error {
+
+    fn from(msg: String) -> Error {
+        Error::Other(msg)
+    }
+
+}
+
+impl From<io::Error> for Error {
+
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+
+}
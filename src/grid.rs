@@ -0,0 +1,240 @@
+use std::fmt::{self, Debug, Display};
+use std::ops::{Index, IndexMut};
+use crate::{Error, Result};
+
+const DIRECTIONS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    (0, -1),           (0, 1),
+    (1, -1),  (1, 0),  (1, 1)
+];
+
+/// A rectangular 2D map, stored row by row and indexed by (row, column).
+///
+/// Indexing with a single `usize` gives a whole row, so `grid[r][c]` works
+/// just like it does on a `Vec<Vec<T>>`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    num_rows: usize,
+    num_cols: usize
+}
+
+impl<T> Grid<T> {
+
+    /// Parses one cell per char, one row per line. All the lines must be equally long.
+    pub fn parse(content: &str, mut parse_cell: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        let mut cells = vec![];
+        let mut num_rows = 0;
+        let mut num_cols = None;
+
+        for line in content.lines() {
+            let row_start = cells.len();
+            for (i, ch) in line.char_indices() {
+                let cell = parse_cell(ch).map_err(|err| err.at(&line[i .. i + ch.len_utf8()]))?;
+                cells.push(cell);
+            }
+
+            let row_len = cells.len() - row_start;
+            match num_cols {
+                None => num_cols = Some(row_len),
+                Some(n) if n != row_len => {
+                    return Err(Error::parse(line, format!("expected {n} cells but found {row_len}")));
+                },
+                _ => ()
+            }
+            num_rows += 1;
+        }
+
+        Ok(Grid { cells, num_rows, num_cols: num_cols.unwrap_or(0) })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        if r < self.num_rows && c < self.num_cols {
+            Some(&self.cells[r * self.num_cols + c])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        if r < self.num_rows && c < self.num_cols {
+            Some(&mut self.cells[r * self.num_cols + c])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self[r]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero size, which only happens with no cells at all
+        self.cells.chunks(self.num_cols.max(1))
+    }
+
+    pub fn col(&self, c: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(c).step_by(self.num_cols.max(1)).take(self.num_rows)
+    }
+
+    /// Iterates all the cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates all the cells along with their (row, column), row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, t)| ((i / self.num_cols, i % self.num_cols), t))
+    }
+
+    /// Finds the (row, column) of the first cell matching `pred`, row by row.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|i| (i / self.num_cols, i % self.num_cols))
+    }
+
+    /// Moves from (r, c) by (dr, dc), if that stays within the grid.
+    pub fn offset(&self, r: usize, c: usize, dr: isize, dc: isize) -> Option<(usize, usize)> {
+        let nr = r.checked_add_signed(dr)?;
+        let nc = c.checked_add_signed(dc)?;
+        if nr < self.num_rows && nc < self.num_cols {
+            Some((nr, nc))
+        } else {
+            None
+        }
+    }
+
+    /// The positions up, left, right and down of (r, c) that are within the grid.
+    pub fn neighbors4(&self, r: usize, c: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS4
+            .iter()
+            .filter_map(move |&(dr, dc)| self.offset(r, c, dr, dc))
+    }
+
+    /// Same as [`Grid::neighbors4`] but also including the diagonals.
+    pub fn neighbors8(&self, r: usize, c: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS8
+            .iter()
+            .filter_map(move |&(dr, dc)| self.offset(r, c, dr, dc))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            num_rows: self.num_rows,
+            num_cols: self.num_cols
+        }
+    }
+
+}
+
+impl<T: Clone> Grid<T> {
+
+    pub fn new(num_rows: usize, num_cols: usize, value: T) -> Grid<T> {
+        Grid { cells: vec![value; num_rows * num_cols], num_rows, num_cols }
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.num_cols, self.num_rows, |r, c| (c, r))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        self.remap(self.num_cols, self.num_rows, |r, c| (self.num_rows - 1 - c, r))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        self.remap(self.num_cols, self.num_rows, |r, c| (c, self.num_cols - 1 - r))
+    }
+
+    // Builds a new grid where each (r, c) takes the value at `src_pos(r, c)` in this one
+    fn remap(&self, num_rows: usize, num_cols: usize, src_pos: impl Fn(usize, usize) -> (usize, usize)) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for r in 0 .. num_rows {
+            for c in 0 .. num_cols {
+                let (src_r, src_c) = src_pos(r, c);
+                cells.push(self[(src_r, src_c)].clone());
+            }
+        }
+
+        Grid { cells, num_rows, num_cols }
+    }
+
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, r: usize) -> &[T] {
+        &self.cells[r * self.num_cols .. (r + 1) * self.num_cols]
+    }
+
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+
+    fn index_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.num_cols .. (r + 1) * self.num_cols]
+    }
+
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        &self[r][c]
+    }
+
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        &mut self[r][c]
+    }
+
+}
+
+impl<T: Display> Display for Grid<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+}
+
+// Tiles usually print as the char they were parsed from, so lay them out the same as Display
+impl<T: Debug> Debug for Grid<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell:?}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+}
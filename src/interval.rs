@@ -0,0 +1,207 @@
+use std::fmt::{self, Debug};
+use num::PrimInt;
+
+/// The integers from `start` (included) up to `end` (excluded).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T
+}
+
+/// A set of integers stored as the intervals covering them.
+///
+/// The intervals are kept normalised: sorted, non-empty and with a gap between any two
+/// of them, as overlapping or adjacent ones get coalesced. Two sets with the same integers
+/// thus always have the same intervals.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>
+}
+
+impl<T: PrimInt> Interval<T> {
+
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn with_length(start: T, length: T) -> Interval<T> {
+        Interval { start, end: start + length }
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() { T::zero() } else { self.end - self.start }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        self.start <= n && n < self.end
+    }
+
+    /// Moves the interval so that `from` would land on `to`.
+    pub fn translate(&self, from: T, to: T) -> Interval<T> {
+        let shift = |n: T| if to >= from { n + (to - from) } else { n - (from - to) };
+        Interval::new(shift(self.start), shift(self.end))
+    }
+
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// The intervals covering the set, in increasing order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |total, i| total + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The `n`th smallest integer in the set, counting from 0.
+    pub fn nth(&self, mut n: T) -> Option<T> {
+        for interval in &self.intervals {
+            if n < interval.len() {
+                return Some(interval.start + n);
+            }
+            n = n - interval.len();
+        }
+        None
+    }
+
+    pub fn contains(&self, n: T) -> bool {
+        // The last interval starting at or before n is the only one that may contain it
+        let after = self.intervals.partition_point(|i| i.start <= n);
+        after > 0 && self.intervals[after - 1].contains(n)
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        *self = self.union(&IntervalSet::from(interval));
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // Both are sorted, so walk them together, always moving past the interval ending first
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let a = self.intervals[i];
+            let b = other.intervals[j];
+            let overlap = Interval::new(a.start.max(b.start), a.end.min(b.end));
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = vec![];
+        let mut j = 0;
+        for &a in &self.intervals {
+            let mut start = a.start;
+            // Skip those of `other` entirely before this interval, which can't affect the next ones either
+            while j < other.intervals.len() && other.intervals[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < a.end {
+                let b = other.intervals[k];
+                if b.start > start {
+                    intervals.push(Interval::new(start, b.start));
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+            if start < a.end {
+                intervals.push(Interval::new(start, a.end));
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// Moves every integer in the set by the same amount, so that `from` would land on `to`.
+    pub fn translate(&self, from: T, to: T) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self.intervals
+                .iter()
+                .map(|i| i.translate(from, to))
+                .collect()
+        }
+    }
+
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from_iter(std::iter::once(interval))
+    }
+
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_by_key(|i| i.start);
+
+        let mut intervals: Vec<Interval<T>> = vec![];
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval)
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+}
+
+impl<T: Debug> Debug for Interval<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}, {:?})", self.start, self.end)
+    }
+
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+
+}
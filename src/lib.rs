@@ -1,44 +1,66 @@
-pub mod str {
-    use std::str::FromStr;
-    
-    pub trait StringOps {
-        fn try_split_once<'a>(&'a self, delimiter: &str) -> Result<(&'a str, &'a str), String>;
-    }
-
-    impl StringOps for str { 
-        fn try_split_once<'a>(&'a self, delimiter: &str) -> Result<(&'a str, &'a str), String> {
-            self.split_once(delimiter)
-                .ok_or(format!("failed to split \"{self}\" by \"{delimiter}\""))
-        }
-    }
-
-    pub fn parse_sequence<T: FromStr>(line: &str) -> Vec<T> where T::Err: std::fmt::Debug {
-        line
-            .split_whitespace()
-            .map(|s| s.parse::<T>().unwrap())
-            .collect()
-    }
-}
+pub mod answers;
+pub mod bench;
+mod char_enum;
+pub mod days;
+pub mod dot;
+pub mod error;
+pub mod grid;
+pub mod interval;
+pub mod str;
+pub mod summary;
 
 use std::time::Instant;
-use std::error::Error;
+use std::fmt::Display;
 use std::fs;
 
-pub type Result<T, E = Box<dyn Error>> = core::result::Result<T, E>;
+pub use error::Error;
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Part {
     One,
     Two
 }
 
-pub fn run<T: std::fmt::Debug>(file_path: &str, part: Part, solve: impl Fn(&str, Part) -> Result<T>) {
+impl Part {
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+
+}
+
+/// A day's puzzle: the input is parsed once and then both parts are
+/// answered from that parsed state.
+pub trait Solution {
+    fn parse(content: &str) -> Result<Self> where Self: Sized;
+
+    fn part1(&self) -> Result<String>;
+
+    fn part2(&self) -> Result<String>;
+
+    fn solve(&self, part: Part) -> Result<String> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2()
+        }
+    }
+}
+
+pub fn run<T: Display>(file_path: &str, part: Part, solve: impl Fn(&str, Part) -> Result<T>) {
     let time = Instant::now();
-    let part_str = match part {
-        Part::One => "part1",
-        Part::Two => "part2"
-    };
-    let content = fs::read_to_string(file_path).unwrap();
-    println!("Running for \"{file_path}\" ({part_str}):");
-    println!("{:?} ({} µs)", solve(&content, part), time.elapsed().as_micros());
+    println!("Running for \"{file_path}\" (part{}):", part.number());
+    let answer = fs::read_to_string(file_path)
+        .map_err(Error::from)
+        .and_then(|content| solve(&content, part).map_err(|err| err.locate(&content)))
+        .map_err(|err| err.in_file(file_path));
+    match answer {
+        Ok(answer) => println!("{answer} ({} µs)", time.elapsed().as_micros()),
+        Err(err) => println!("Error: {err} ({} µs)", time.elapsed().as_micros())
+    }
     println!();
 }
@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use crate::{Error, Result};
+
+pub trait StringOps {
+    fn try_split_once<'a>(&'a self, delimiter: &str) -> Result<(&'a str, &'a str)>;
+
+    fn try_parse<T: FromStr>(&self) -> Result<T> where T::Err: Display;
+
+    fn try_parse_chars<T: TryFrom<char, Error = Error>>(&self) -> Result<Vec<T>>;
+
+    /// Parses a whitespace-separated sequence, like "79 14 55 13".
+    fn try_parse_sequence<T: FromStr>(&self) -> Result<Vec<T>> where T::Err: Display;
+
+    /// Parses a list split by `separator`, ignoring the whitespace around each item, like "1,1,3".
+    fn try_parse_list<T: FromStr>(&self, separator: &str) -> Result<Vec<T>> where T::Err: Display;
+
+    /// Strips a leading label, like "Time:", along with the whitespace after it.
+    fn strip_label<'a>(&'a self, label: &str) -> Result<&'a str>;
+
+    /// Splits into the blocks of lines separated by one or more blank lines.
+    fn split_blocks(&self) -> Vec<&str>;
+}
+
+impl StringOps for str { 
+    fn try_split_once<'a>(&'a self, delimiter: &str) -> Result<(&'a str, &'a str)> {
+        self.split_once(delimiter)
+            .ok_or_else(|| Error::parse(self, format!("expected \"{delimiter}\"")))
+    }
+
+    fn try_parse<T: FromStr>(&self) -> Result<T> where T::Err: Display {
+        self.parse()
+            .map_err(|err| Error::parse(self, format!("{err}")))
+    }
+
+    fn try_parse_chars<T: TryFrom<char, Error = Error>>(&self) -> Result<Vec<T>> {
+        self.char_indices()
+            .map(|(i, c)| T::try_from(c).map_err(|err| err.at(&self[i .. i + c.len_utf8()])))
+            .collect()
+    }
+
+    fn try_parse_sequence<T: FromStr>(&self) -> Result<Vec<T>> where T::Err: Display {
+        self.split_whitespace()
+            .map(|s| s.try_parse())
+            .collect()
+    }
+
+    fn try_parse_list<T: FromStr>(&self, separator: &str) -> Result<Vec<T>> where T::Err: Display {
+        self.split(separator)
+            .map(|s| s.trim().try_parse())
+            .collect()
+    }
+
+    fn strip_label<'a>(&'a self, label: &str) -> Result<&'a str> {
+        self.trim_start()
+            .strip_prefix(label)
+            .map(str::trim_start)
+            .ok_or_else(|| Error::parse(self, format!("expected \"{label}\"")))
+    }
+
+    fn split_blocks(&self) -> Vec<&str> {
+        let mut blocks = vec![];
+        let mut block_start = None;
+        let mut offset = 0;
+
+        for line in self.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if let Some(start) = block_start.take() {
+                    blocks.push(&self[start .. offset]);
+                }
+            } else if block_start.is_none() {
+                block_start = Some(offset);
+            }
+            offset += line.len();
+        }
+
+        if let Some(start) = block_start {
+            blocks.push(&self[start ..]);
+        }
+
+        blocks
+    }
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::time::Instant;
+use rayon::prelude::*;
+use serde::Serialize;
+use crate::{Result, Part};
+use crate::answers::ExpectedAnswer;
+use crate::days::Day;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There's no known answer to compare against
+    Unchecked
+}
+
+/// The outcome of running one day's part against its real input.
+#[derive(Serialize, Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, or the error message if it failed
+    pub answer: String,
+    pub parse_us: u128,
+    pub solve_us: u128,
+    pub status: Status
+}
+
+/// Runs both parts of every given day against `inputs/dayNN`, in parallel, checking
+/// the answers against the expected ones. Timings are thus only indicative, as the
+/// runs compete for the CPU.
+pub fn run_all(days: &[&Day], expected_answers: &[ExpectedAnswer]) -> Vec<Outcome> {
+    let runs: Vec<_> = days
+        .iter()
+        .flat_map(|&day| [(day, Part::One), (day, Part::Two)])
+        .collect();
+
+    runs
+        .into_par_iter()
+        .map(|(day, part)| {
+            let input = format!("day{:02}", day.number);
+            let (answer, parse_us, solve_us) = match run_timed(day, part, &input) {
+                Ok(timed) => timed,
+                Err(err) => (Err(err.to_string()), 0, 0)
+            };
+
+            let expected = expected_answers
+                .iter()
+                .find(|e| e.day == day.number && e.part == part && e.input == input);
+            let status = match (expected, &answer) {
+                (None, _) => Status::Unchecked,
+                (Some(e), Ok(answer)) if e.answer == *answer => Status::Pass,
+                _ => Status::Fail
+            };
+
+            Outcome {
+                day: day.number,
+                part: part.number(),
+                answer: answer.unwrap_or_else(|err| format!("Error: {err}")),
+                parse_us,
+                solve_us,
+                status
+            }
+        })
+        .collect()
+}
+
+// Gives the answer (or its error) along with the parse and solve times, failing only if the input can't be parsed
+fn run_timed(day: &Day, part: Part, input: &str) -> Result<(Result<String, String>, u128, u128)> {
+    let content = fs::read_to_string(format!("inputs/{input}"))?;
+
+    let time = Instant::now();
+    let solution = day.parse(&content)?;
+    let parse_us = time.elapsed().as_micros();
+
+    let time = Instant::now();
+    let answer = solution.solve(part).map_err(|err| err.to_string());
+    let solve_us = time.elapsed().as_micros();
+
+    Ok((answer, parse_us, solve_us))
+}
+
+const HEADERS: [&str; 6] = ["Day", "Part", "Answer", "Parse (µs)", "Solve (µs)", "Status"];
+
+fn cells(outcome: &Outcome) -> [String; 6] {
+    [
+        outcome.day.to_string(),
+        outcome.part.to_string(),
+        outcome.answer.clone(),
+        outcome.parse_us.to_string(),
+        outcome.solve_us.to_string(),
+        match outcome.status {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Unchecked => "-"
+        }.to_string()
+    ]
+}
+
+/// Lays the outcomes out as a plain text table, with the columns aligned.
+pub fn to_table(outcomes: &[Outcome]) -> String {
+    let rows: Vec<_> = outcomes.iter().map(cells).collect();
+    let mut widths = HEADERS.map(|h| h.chars().count());
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String]| row
+        .iter()
+        .zip(widths)
+        .map(|(cell, w)| format!("{cell:<w$}"))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string();
+
+    let mut table = format_row(&HEADERS.map(String::from));
+    table.push('\n');
+    table.push_str(&format_row(&widths.map(|w| "-".repeat(w))));
+    table.push('\n');
+    for row in &rows {
+        table.push_str(&format_row(row));
+        table.push('\n');
+    }
+    table
+}
+
+pub fn to_markdown(outcomes: &[Outcome]) -> String {
+    let format_row = |row: &[String]| format!("| {} |\n", row.join(" | "));
+
+    let mut markdown = format_row(&HEADERS.map(String::from));
+    markdown.push_str(&format_row(&HEADERS.map(|_| "---".to_string())));
+    for outcome in outcomes {
+        // Pipes would otherwise split the cell
+        markdown.push_str(&format_row(&cells(outcome).map(|c| c.replace('|', "\\|"))));
+    }
+    markdown
+}
+
+pub fn to_json(outcomes: &[Outcome]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(outcomes).map_err(|err| err.to_string())?)
+}
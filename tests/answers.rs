@@ -0,0 +1,52 @@
+use adventofcode2023::{answers, days, Result, Solution};
+use adventofcode2023::answers::ExpectedAnswer;
+use adventofcode2023::days::day21::Garden;
+use std::fs;
+
+fn parse(expected: &ExpectedAnswer, content: &str) -> Result<Box<dyn Solution>> {
+    match (expected.day, &expected.steps[..]) {
+        (21, steps) if !steps.is_empty() => Ok(Box::new(Garden::parse(content)?.with_steps(steps.to_vec()))),
+        (day, []) => days::get(day)
+            .unwrap_or_else(|| panic!("day {day} is not implemented"))
+            .parse(content),
+        (day, _) => panic!("day {day} doesn't take a number of steps")
+    }
+}
+
+#[test]
+fn every_solver_gives_the_expected_answers() {
+    let expected_answers = answers::load("inputs/answers").unwrap();
+    assert!(!expected_answers.is_empty(), "no expected answers were found");
+
+    let mut mismatches = vec![];
+    for expected in &expected_answers {
+        let content = fs::read_to_string(expected.file_path()).unwrap();
+        let actual = parse(expected, &content)
+            .and_then(|solution| solution.solve(expected.part))
+            .unwrap_or_else(|err| format!("Error: {err}"));
+
+        if actual != expected.answer {
+            mismatches.push(format!(
+                "day {} {:?} on {}: expected {}, got {}",
+                expected.day, expected.part, expected.input, expected.answer, actual
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "mismatched answers:\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn every_input_has_expected_answers() {
+    let expected_answers = answers::load("inputs/answers").unwrap();
+
+    let mut uncovered: Vec<_> = fs::read_dir("inputs")
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|input| input.starts_with("day"))
+        .filter(|input| !expected_answers.iter().any(|e| e.input == *input))
+        .collect();
+    uncovered.sort();
+
+    assert!(uncovered.is_empty(), "inputs without expected answers: {}", uncovered.join(", "));
+}
@@ -0,0 +1,86 @@
+use adventofcode2023::Solution;
+use adventofcode2023::days::day05::{Almanac, Part2Strategy};
+use adventofcode2023::interval::{Interval, IntervalSet};
+use std::fs;
+
+fn parse_almanac(file_path: &str) -> Almanac {
+    Almanac::parse(&fs::read_to_string(file_path).unwrap()).unwrap()
+}
+
+#[test]
+fn planting_the_example_seeds_matches_the_puzzle() {
+    let almanac = parse_almanac("inputs/day05_example");
+    for (seed, location) in [(79, 82), (14, 43), (55, 86), (13, 35)] {
+        assert_eq!(almanac.where_to_plant(seed), location, "for seed {seed}");
+    }
+}
+
+#[test]
+fn planting_ranges_matches_planting_each_seed() {
+    let almanac = parse_almanac("inputs/day05_example");
+    let seeds = IntervalSet::from_iter([Interval::with_length(79, 14), Interval::with_length(55, 13)]);
+    let expected: IntervalSet<u64> = (55 .. 93)
+        .filter(|&seed| seeds.contains(seed))
+        .map(|seed| Interval::with_length(almanac.where_to_plant(seed as u32), 1))
+        .collect();
+    assert_eq!(almanac.where_to_plant_range(&seeds), expected);
+}
+
+#[test]
+fn seeds_planted_at_a_location_are_all_that_go_there() {
+    let almanac = parse_almanac("inputs/day05_example");
+    let locations = IntervalSet::from(Interval::new(40, 50));
+    let seeds = almanac.seeds_planted_at(&locations);
+    for seed in 0 .. 200 {
+        let location = almanac.where_to_plant(seed);
+        assert_eq!(seeds.contains(seed as u64), locations.contains(location), "for seed {seed} at {location}");
+    }
+}
+
+#[test]
+fn converting_between_any_two_categories_goes_through_those_in_between() {
+    let almanac = parse_almanac("inputs/day05_example");
+    assert_eq!(almanac.convert("soil", "humidity", 81).unwrap(), 78);
+    assert_eq!(almanac.convert("seed", "seed", 79).unwrap(), 79);
+    assert!(almanac.convert("location", "seed", 82).is_err());
+}
+
+#[test]
+fn maps_can_come_in_any_order() {
+    let content = fs::read_to_string("inputs/day05_example").unwrap();
+    let mut blocks: Vec<_> = content.split("\n\n").map(str::trim).collect();
+    blocks[1 ..].reverse();
+    let almanac = Almanac::parse(&blocks.join("\n\n")).unwrap();
+    assert_eq!(almanac.part1().unwrap(), "35");
+    assert_eq!(almanac.part2().unwrap(), "46");
+}
+
+#[test]
+fn a_missing_category_is_rejected() {
+    let content = fs::read_to_string("inputs/day05_example").unwrap();
+    let content = content.replace("water-to-light", "ocean-to-light");
+    let error = Almanac::parse(&content).err().expect("missing water-to-light map").to_string();
+    assert_eq!(error, "No map converts water on the way from seed to location");
+}
+
+#[test]
+fn cyclic_categories_are_rejected() {
+    let content = fs::read_to_string("inputs/day05_example").unwrap();
+    let content = content.replace("light-to-temperature", "light-to-soil");
+    let error = Almanac::parse(&content).err().expect("cyclic maps").to_string();
+    assert_eq!(error, "Categories cycle through seed -> soil -> fertilizer -> water -> light -> soil");
+}
+
+#[test]
+fn every_strategy_finds_the_same_lowest_location() {
+    for strategy in [Part2Strategy::EverySeed, Part2Strategy::EverySeedInParallel, Part2Strategy::Ranges, Part2Strategy::Verify] {
+        let almanac = parse_almanac("inputs/day05_example").with_strategy(strategy);
+        assert_eq!(almanac.part2().unwrap(), "46", "with {strategy:?}");
+    }
+}
+
+#[test]
+fn planting_sampled_seeds_one_by_one_agrees_with_the_ranges() {
+    let almanac = parse_almanac("inputs/day05").with_strategy(Part2Strategy::Verify).with_sample_seed(2023);
+    assert_eq!(almanac.part2().unwrap(), almanac.with_strategy(Part2Strategy::Ranges).part2().unwrap());
+}
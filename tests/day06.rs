@@ -0,0 +1,43 @@
+use adventofcode2023::Solution;
+use adventofcode2023::days::day06::RaceSheet;
+
+fn ways_to_beat_record(duration: u64, record_distance: u64) -> String {
+    let sheet = RaceSheet::parse(&format!("Time: {duration}\nDistance: {record_distance}")).unwrap();
+    sheet.part2().unwrap()
+}
+
+#[test]
+fn tying_the_record_does_not_beat_it() {
+    // Holding for 10 or 20ms ties at 200mm, leaving the 9 in between
+    assert_eq!(ways_to_beat_record(30, 200), "9");
+    assert_eq!(ways_to_beat_record(4, 4), "0");
+    assert_eq!(ways_to_beat_record(4, 3), "1");
+}
+
+#[test]
+fn a_record_out_of_reach_has_no_ways_to_beat_it() {
+    assert_eq!(ways_to_beat_record(7, 13), "0");
+    assert_eq!(ways_to_beat_record(0, 0), "0");
+    assert_eq!(ways_to_beat_record(1, 0), "0");
+    assert_eq!(ways_to_beat_record(2, 0), "1");
+}
+
+#[test]
+fn races_beyond_floating_point_precision_are_exact() {
+    // Only holding for exactly half the race beats it, by 1mm, with the neighbors tying it
+    assert_eq!(ways_to_beat_record(1 << 32, (1 << 62) - 1), "1");
+    assert_eq!(ways_to_beat_record(u64::MAX, 0), (u64::MAX - 1).to_string());
+    // Same around 3e9 squared, where holding for one more or less than half the race loses 1mm each
+    assert_eq!(ways_to_beat_record(6_000_000_000, 9_000_000_000_000_000_000 - 1), "1");
+    assert_eq!(ways_to_beat_record(6_000_000_000, 9_000_000_000_000_000_000 - 2), "3");
+}
+
+#[test]
+fn counting_matches_trying_every_hold_time() {
+    for duration in 0 .. 40 {
+        for record_distance in 0 ..= duration * duration / 4 + 1 {
+            let expected = (0 ..= duration).filter(|v| v * (duration - v) > record_distance).count();
+            assert_eq!(ways_to_beat_record(duration, record_distance), expected.to_string(), "for {duration}ms and {record_distance}mm");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use adventofcode2023::days;
+use adventofcode2023::days::day19::Workflows;
+use adventofcode2023::error::{Error, Location};
+use adventofcode2023::str::StringOps;
+use std::fs;
+
+fn parse_error(block: &str) -> (Location, String) {
+    match Workflows::parse(block).err().expect("expected the workflows to be rejected").locate(block) {
+        Error::Parse(err) => (err.location.unwrap(), err.message),
+        err => panic!("expected a parse error, got: {err}")
+    }
+}
+
+#[test]
+fn workflows_print_back_as_they_were_given() {
+    for file_path in ["inputs/day19_example", "inputs/day19"] {
+        let content = fs::read_to_string(file_path).unwrap();
+        let workflows_block = content.split_blocks()[0];
+
+        let workflows = Workflows::parse(workflows_block).unwrap();
+        assert_eq!(workflows.to_string().trim_end(), workflows_block.trim_end());
+    }
+}
+
+#[test]
+fn workflows_with_unknown_targets_are_rejected() {
+    let (location, message) = parse_error("in{a<2006:qkq,A}");
+    assert_eq!(location, Location { line: 1, column: 11 });
+    assert_eq!(message, "workflow in sends parts to an unknown workflow");
+}
+
+#[test]
+fn workflows_not_reachable_from_in_are_allowed() {
+    let content = "in{a<2006:R,A}\npx{m>2090:R,A}\n\n{x=787,m=2655,a=3000,s=2876}\n";
+    let solution = days::get(19).unwrap().parse(content).unwrap();
+    assert_eq!(solution.part1().unwrap(), "9318");
+}
+
+#[test]
+fn workflows_going_in_circles_are_rejected() {
+    let (location, message) = parse_error("in{a<2006:px,A}\npx{m>2090:A,qs}\nqs{s>3448:R,px}");
+    assert_eq!(location, Location { line: 3, column: 13 });
+    assert_eq!(message, "workflows cycle through in -> px -> qs -> px");
+}
+
+#[test]
+fn ratings_above_every_part_are_never_matched() {
+    let content = "in{x>4294967295:A,R}\n\n{x=787,m=2655,a=1222,s=2876}\n";
+    let solution = days::get(19).unwrap().parse(content).unwrap();
+    assert_eq!(solution.part1().unwrap(), "0");
+    assert_eq!(solution.part2().unwrap(), "0");
+}
@@ -0,0 +1,83 @@
+use adventofcode2023::{Part, Solution};
+use adventofcode2023::days::day20::ModuleNetwork;
+use std::cell::RefCell;
+use std::fs;
+use std::rc::Rc;
+
+fn part2_error(content: &str) -> String {
+    let modules = ModuleNetwork::parse(content).unwrap();
+    modules.part2().expect_err("expected part 2 to fail").to_string()
+}
+
+#[test]
+fn rx_must_be_fed_by_a_single_module() {
+    let error = part2_error("broadcaster -> a\n%a -> b\n&b -> c");
+    assert_eq!(error, "Expected a single module sending pulses to rx, found 0");
+}
+
+#[test]
+fn rx_must_be_fed_by_a_conjunction() {
+    let error = part2_error("broadcaster -> a\n%a -> rx");
+    assert_eq!(error, "Expected a, which sends pulses to rx, to be a conjunction");
+}
+
+#[test]
+fn conjunction_inputs_must_send_high_pulses_periodically() {
+    // `a` flips on every other press, sending a high pulse on presses 1, 3, 5...
+    let error = part2_error("broadcaster -> a\n%a -> con\n&con -> rx");
+    assert_eq!(error, "Expected a to send high pulses to con every 1 presses, but got one at 3");
+}
+
+#[test]
+fn pressing_the_button_gives_the_pulses_in_order() {
+    let network = ModuleNetwork::parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a").unwrap();
+    let mut state = network.initial_state();
+
+    let pulses: Vec<_> = network.press_button(&mut state).iter().map(|p| p.to_string()).collect();
+    assert_eq!(pulses, [
+        "button -low-> broadcaster",
+        "broadcaster -low-> a",
+        "broadcaster -low-> b",
+        "broadcaster -low-> c",
+        "a -high-> b",
+        "b -high-> c",
+        "c -high-> inv",
+        "inv -low-> a",
+        "a -low-> b",
+        "b -low-> c",
+        "c -low-> inv",
+        "inv -high-> a",
+    ]);
+    assert_eq!(state, network.initial_state());
+}
+
+#[test]
+fn network_states_tell_apart_presses() {
+    let network = ModuleNetwork::parse("broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output").unwrap();
+    let mut state = network.initial_state();
+
+    let mut states = vec![state.clone()];
+    for _ in 0 .. 4 {
+        network.press_button(&mut state);
+        states.push(state.clone());
+    }
+
+    assert_eq!(states[4], states[0]);
+    assert!(states[1 .. 4].iter().all(|s| *s != states[0]));
+}
+
+#[test]
+fn the_event_log_keeps_the_pulses_of_both_parts() {
+    let content = fs::read_to_string("inputs/day20").unwrap();
+    let log = Rc::new(RefCell::new(vec![]));
+    // Like the binary does, each part gets a network of its own sharing the log
+    for part in [Part::One, Part::Two] {
+        let network = ModuleNetwork::parse(&content).unwrap().with_event_log(log.clone());
+        network.solve(part).unwrap();
+    }
+
+    let log = String::from_utf8(log.take()).unwrap();
+    let sections: Vec<_> = log.lines().filter(|l| l.starts_with('#')).collect();
+    assert_eq!(sections, ["# part 1", "# part 2"]);
+    assert_eq!(log.lines().filter(|l| *l == "1 button -low-> broadcaster").count(), 2);
+}
@@ -0,0 +1,45 @@
+use adventofcode2023::Solution;
+use adventofcode2023::days::day21::Garden;
+use std::fs;
+
+fn parse_garden(file_path: &str) -> Garden {
+    Garden::parse(&fs::read_to_string(file_path).unwrap()).unwrap()
+}
+
+#[test]
+fn walking_the_infinite_example_matches_the_puzzle() {
+    let garden = parse_garden("inputs/day21_example");
+    for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+        assert_eq!(garden.count_reachable_plots_on_infinite_grid(steps), expected, "after {steps} steps");
+    }
+}
+
+#[test]
+fn extrapolating_matches_walking_the_infinite_garden() {
+    let garden = parse_garden("inputs/day21");
+    for steps in [458, 500] {
+        let expected = garden.count_reachable_plots_on_infinite_grid(steps);
+        assert_eq!(garden.extrapolate_reachable_plots_on_infinite_grid(steps).unwrap(), expected, "after {steps} steps");
+    }
+}
+
+#[test]
+fn extrapolating_requires_a_clear_row_and_column_through_the_start() {
+    let garden = parse_garden("inputs/day21_example");
+    assert!(garden.extrapolate_reachable_plots_on_infinite_grid(5000).is_err());
+}
+
+#[test]
+fn distance_map_matches_walking_while_within_the_garden() {
+    // S is in the middle of a 131x131 garden, so the first 65 steps can't leave it
+    let garden = parse_garden("inputs/day21");
+    for steps in [0, 1, 2, 10, 33, 64, 65] {
+        assert_eq!(garden.count_reachable_plots(steps), garden.count_reachable_plots_on_infinite_grid(steps), "after {steps} steps");
+    }
+}
+
+#[test]
+fn several_steps_are_answered_at_once() {
+    let garden = parse_garden("inputs/day21_example").with_steps(vec![1, 2, 3, 6]);
+    assert_eq!(garden.part1().unwrap(), "1: 2, 2: 4, 3: 6, 6: 16");
+}
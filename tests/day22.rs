@@ -0,0 +1,39 @@
+use adventofcode2023::Solution;
+use adventofcode2023::days::day22::SandStack;
+use std::fs;
+
+#[test]
+fn disintegrating_each_brick_makes_the_others_fall() {
+    let stack = SandStack::parse(&fs::read_to_string("inputs/day22_example").unwrap()).unwrap();
+
+    let mut falling_bricks = stack.count_falling_bricks();
+    falling_bricks.sort();
+    assert_eq!(falling_bricks, [("A", 6), ("B", 0), ("C", 0), ("D", 0), ("E", 0), ("F", 1), ("G", 0)]);
+    assert_eq!(stack.part2().unwrap(), "7");
+}
+
+#[test]
+fn settled_bricks_are_dumped_as_text_and_obj() {
+    let stack = SandStack::parse("1,0,1~1,2,1\n0,0,5~2,0,5").unwrap();
+
+    assert_eq!(stack.to_text(), "1,0,1~1,2,1\n0,0,2~2,0,2\n");
+
+    let obj = stack.to_obj();
+    assert_eq!(obj.lines().filter(|l| l.starts_with("o ")).count(), 2);
+    assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 16);
+    assert!(obj.contains("v 3 1 3\n"));
+    assert!(obj.lines().any(|l| l == "f 9 11 15 13"));
+}
+
+#[test]
+fn bricks_beyond_the_alphabet_get_longer_labels() {
+    // A single column, given top down, where each brick rests on the next one
+    let content: String = (1 ..= 28).rev().map(|z| format!("0,0,{}~0,0,{}\n", 10 * z, 10 * z)).collect();
+    let stack = SandStack::parse(&content).unwrap();
+
+    let falling_bricks = stack.count_falling_bricks();
+    assert!(falling_bricks.contains(&("A", 0)));
+    assert!(falling_bricks.contains(&("Z", 25)));
+    assert!(falling_bricks.contains(&("AB", 27)));
+    assert_eq!(stack.part1().unwrap(), "1");
+}
@@ -0,0 +1,29 @@
+use adventofcode2023::dot;
+
+#[test]
+fn module_networks_export_their_types_and_connections() {
+    let graph = dot::graph(20, "broadcaster -> a\n%a -> con\n&con -> rx").unwrap();
+    assert_eq!(graph.to_string(), "\
+digraph \"modules\" {
+    \"broadcaster\" [label=\"broadcaster\", shape=house];
+    \"broadcaster\" -> \"a\";
+    \"a\" [label=\"%a\", shape=box];
+    \"a\" -> \"con\";
+    \"con\" [label=\"&con\", shape=invtriangle];
+    \"con\" -> \"rx\";
+    \"rx\" [label=\"rx\", shape=doublecircle];
+}
+");
+}
+
+#[test]
+fn workflow_edges_are_labelled_with_their_conditions() {
+    let graph = dot::graph(19, "in{a<2006:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap().to_string();
+    assert!(graph.contains("\"in\" -> \"A\" [label=\"a<2006\"];"));
+    assert!(graph.contains("\"in\" -> \"R\" [label=\"else\"];"));
+}
+
+#[test]
+fn days_without_a_graph_are_rejected() {
+    assert!(dot::graph(5, "").is_err());
+}
@@ -0,0 +1,61 @@
+use adventofcode2023::days;
+use adventofcode2023::error::{Error, Location};
+
+#[test]
+fn malformed_input_is_located() {
+    let content = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 grean\n";
+    let err = days::get(2).unwrap().parse(content).err().unwrap();
+
+    match err.locate(content).in_file("inputs/day02") {
+        Error::Parse(err) => {
+            assert_eq!(err.file.as_deref(), Some("inputs/day02"));
+            assert_eq!(err.location, Some(Location { line: 2, column: 19 }));
+            assert_eq!(err.token, "grean");
+        },
+        err => panic!("expected a parse error, got: {err}")
+    }
+}
+
+fn parse_error_location(day: u8, content: &str) -> (Location, String) {
+    let err = days::get(day).unwrap().parse(content).err().unwrap();
+    match err.locate(content) {
+        Error::Parse(err) => (err.location.unwrap(), err.token),
+        err => panic!("expected a parse error, got: {err}")
+    }
+}
+
+#[test]
+fn lines_without_digits_are_located() {
+    let (location, token) = parse_error_location(1, "1abc2\npqrstu\n");
+    assert_eq!(location, Location { line: 2, column: 1 });
+    assert_eq!(token, "pqrstu");
+}
+
+#[test]
+fn malformed_nodes_are_located() {
+    let (location, token) = parse_error_location(8, "LR\n\nAAA = (BBB, ZZZ)\nBBB = BBB, ZZZ\nZZZ = (ZZZ, ZZZ)\n");
+    assert_eq!(location, Location { line: 4, column: 7 });
+    assert_eq!(token, "BBB, ZZZ");
+
+    let (location, token) = parse_error_location(8, "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n");
+    assert_eq!(location, Location { line: 3, column: 8 });
+    assert_eq!(token, "BBB");
+}
+
+#[test]
+fn malformed_steps_are_located() {
+    let (location, token) = parse_error_location(15, "rn=1,cm-,qp=x\n");
+    assert_eq!(location, Location { line: 1, column: 13 });
+    assert_eq!(token, "x");
+
+    let (location, token) = parse_error_location(15, "rn=1,cm\n");
+    assert_eq!(location, Location { line: 1, column: 6 });
+    assert_eq!(token, "cm");
+}
+
+#[test]
+fn start_without_two_pipes_is_an_error() {
+    let solution = days::get(10).unwrap().parse(".|.\n-S-\n.|.\n").unwrap();
+    assert!(solution.part1().is_err());
+    assert!(solution.part2().is_err());
+}
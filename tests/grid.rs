@@ -0,0 +1,40 @@
+use adventofcode2023::grid::Grid;
+
+fn sample() -> Grid<char> {
+    Grid::parse("abc\ndef\n", Ok).unwrap()
+}
+
+#[test]
+fn parses_rows_and_columns() {
+    let grid = sample();
+    assert_eq!(grid.num_rows(), 2);
+    assert_eq!(grid.num_cols(), 3);
+    assert_eq!(grid[1][2], 'f');
+    assert_eq!(grid[(0, 1)], 'b');
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.col(1).collect::<String>(), "be");
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+}
+
+#[test]
+fn rejects_ragged_rows() {
+    assert!(Grid::parse("abc\nde\n", Ok).is_err());
+}
+
+#[test]
+fn neighbors_stay_within_bounds() {
+    let grid = sample();
+    assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors8(0, 1).collect::<Vec<_>>(), [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    assert_eq!(grid.offset(1, 2, 0, 1), None);
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid = sample();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+}
@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+use adventofcode2023::interval::{Interval, IntervalSet};
+use proptest::prelude::*;
+
+// Small enough bounds that sets can be checked against the integers they hold
+fn interval_set() -> impl Strategy<Value = IntervalSet<u16>> {
+    prop::collection::vec((0 .. 60_u16, 0 .. 15_u16), 0 .. 6)
+        .prop_map(|bounds| bounds
+            .into_iter()
+            .map(|(start, length)| Interval::with_length(start, length))
+            .collect())
+}
+
+fn points(set: &IntervalSet<u16>) -> BTreeSet<u16> {
+    set.intervals().iter().flat_map(|i| i.start .. i.end).collect()
+}
+
+fn is_normalised(set: &IntervalSet<u16>) -> bool {
+    let intervals = set.intervals();
+    intervals.iter().all(|i| !i.is_empty())
+        && intervals.windows(2).all(|w| w[0].end < w[1].start)
+}
+
+proptest! {
+    #[test]
+    fn union_holds_the_points_of_either(a in interval_set(), b in interval_set()) {
+        let union = a.union(&b);
+        prop_assert!(is_normalised(&union));
+        prop_assert_eq!(points(&union), &points(&a) | &points(&b));
+    }
+
+    #[test]
+    fn intersection_holds_the_points_of_both(a in interval_set(), b in interval_set()) {
+        let intersection = a.intersection(&b);
+        prop_assert!(is_normalised(&intersection));
+        prop_assert_eq!(points(&intersection), &points(&a) & &points(&b));
+    }
+
+    #[test]
+    fn difference_holds_the_points_only_in_the_first(a in interval_set(), b in interval_set()) {
+        let difference = a.difference(&b);
+        prop_assert!(is_normalised(&difference));
+        prop_assert_eq!(points(&difference), &points(&a) - &points(&b));
+    }
+
+    #[test]
+    fn sets_with_the_same_points_are_equal(a in interval_set(), b in interval_set()) {
+        prop_assert_eq!(a == b, points(&a) == points(&b));
+    }
+
+    #[test]
+    fn length_and_membership_match_the_points(a in interval_set(), n in 0 .. 80_u16) {
+        prop_assert_eq!(a.len() as usize, points(&a).len());
+        prop_assert_eq!(a.contains(n), points(&a).contains(&n));
+        prop_assert_eq!(a.min(), points(&a).first().copied());
+    }
+
+    #[test]
+    fn translation_moves_every_point(a in interval_set(), from in 0 .. 100_u16, to in 100 .. 200_u16) {
+        let there = a.translate(from, to);
+        prop_assert_eq!(points(&there), points(&a).iter().map(|n| n + to - from).collect::<BTreeSet<_>>());
+        prop_assert_eq!(there.translate(to, from), a);
+    }
+}
+
+#[test]
+fn adjacent_intervals_are_coalesced() {
+    let set: IntervalSet<u32> = [Interval::new(5, 8), Interval::new(0, 5), Interval::new(10, 12)].into_iter().collect();
+    assert_eq!(set.intervals(), [Interval::new(0, 8), Interval::new(10, 12)]);
+}
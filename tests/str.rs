@@ -0,0 +1,21 @@
+use adventofcode2023::str::StringOps;
+
+#[test]
+fn parses_sequences_and_lists() {
+    assert_eq!("79 14  55 13".try_parse_sequence::<u32>().unwrap(), [79, 14, 55, 13]);
+    assert_eq!("1, 1,3".try_parse_list::<u32>(",").unwrap(), [1, 1, 3]);
+    assert!("1 x 3".try_parse_sequence::<u32>().is_err());
+}
+
+#[test]
+fn strips_labels() {
+    assert_eq!("Time:      7  15   30".strip_label("Time:").unwrap(), "7  15   30");
+    assert!("Distance: 9".strip_label("Time:").is_err());
+}
+
+#[test]
+fn splits_blocks_on_blank_lines() {
+    let content = "a\nb\n\nc\n  \n\nd\n\n";
+    assert_eq!(content.split_blocks(), ["a\nb\n", "c\n", "d\n"]);
+    assert_eq!("".split_blocks(), Vec::<&str>::new());
+}
@@ -0,0 +1,19 @@
+use adventofcode2023::{answers, days, summary};
+use adventofcode2023::summary::Status;
+
+#[test]
+fn summary_checks_answers_and_formats_them() {
+    let expected_answers = answers::load("inputs/answers").unwrap();
+    let days = [days::get(1).unwrap(), days::get(2).unwrap()];
+
+    let outcomes = summary::run_all(&days, &expected_answers);
+    assert_eq!(outcomes.len(), 4);
+    assert!(outcomes.iter().all(|o| o.status == Status::Pass));
+
+    let markdown = summary::to_markdown(&outcomes);
+    assert_eq!(markdown.lines().count(), 6);
+    assert!(markdown.starts_with("| Day | Part | Answer |"));
+
+    let json: serde_json::Value = serde_json::from_str(&summary::to_json(&outcomes).unwrap()).unwrap();
+    assert_eq!(json[0]["status"], "pass");
+}